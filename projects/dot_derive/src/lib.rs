@@ -13,8 +13,8 @@ pub fn derive_dot(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let mut field_impl = vec![];
     let mut graph_impl = vec![];
 
-    match input.data {
-        Data::Struct(data) => for field in data.fields {
+    if let Data::Struct(data) = input.data {
+        for field in data.fields {
             for attr in &field.attrs {
                 let name = match &field.ident {
                     Some(ident) => ident,
@@ -43,9 +43,7 @@ pub fn derive_dot(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
                     }
                 }
             }
-        },
-
-        _ => (),
+        }
     }
 
     let expanded = quote! {
@@ -1,10 +1,31 @@
-use argparse;
+use std::str::FromStr;
+
+#[derive(Default)]
+pub enum ErrorFormat {
+    #[default]
+    Human,
+    Json,
+}
+
+impl FromStr for ErrorFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "human" => Ok(ErrorFormat::Human),
+            "json" => Ok(ErrorFormat::Json),
+
+            _ => Err(format!("invalid error format `{}`", format)),
+        }
+    }
+}
 
 #[derive(Default)]
 pub struct Config {
     pub emit_ast: bool,
     pub emit_ast_graph: bool,
     pub emit_llvm: bool,
+    pub error_format: ErrorFormat,
     pub file_name: String,
 }
 
@@ -33,6 +54,12 @@ impl Config {
                 "Emit ast in graphviz form",
             );
 
+            ap.refer(&mut config.error_format).add_option(
+                &["--error-format"],
+                argparse::Store,
+                "Format of reported errors: human (default) or json",
+            );
+
             ap.refer(&mut config.file_name)
                 .add_argument("file", argparse::Store, "File to parse")
                 .required();
@@ -40,6 +67,6 @@ impl Config {
             ap.parse_args_or_exit();
         }
 
-        return config;
+        config
     }
 }
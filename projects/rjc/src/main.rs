@@ -1,14 +1,23 @@
 #![feature(iterator_try_collect)]
 #![allow(dead_code)]
 
-use std::io;
+use std::{io, process};
+
+use config::{Config, ErrorFormat};
+use rjc_ast::AST;
+use rjc_diagnostics::Diagnostics;
 
 mod config;
 
 fn main() -> io::Result<()> {
-    let config = config::Config::new();
+    let config = Config::new();
+    let mut diagnostics = Diagnostics::default();
+
+    let pool = rjc_parser::from_file(config.file_name.clone(), &mut diagnostics)?;
 
-    let pool = rjc_parser::from_file(config.file_name)?;
+    if diagnostics.has_errors() {
+        fail(&config, &pool, &diagnostics)?;
+    }
 
     if config.emit_ast {
         println!("{:#?}", pool);
@@ -20,11 +29,36 @@ fn main() -> io::Result<()> {
         pool.graph(&mut out)?;
     }
 
-    // let mut context: ast::IRContext = Default::default();
+    let resolution = rjc_resolver::resolve(&pool, &mut diagnostics);
+    let types = rjc_typer::check(&pool, &resolution, &mut diagnostics);
 
-    // if config.emit_llvm {
-    //     return file.ir(&mut out, &mut context);
-    // }
+    if diagnostics.has_errors() {
+        fail(&config, &pool, &diagnostics)?;
+    }
+
+    if config.emit_llvm {
+        rjc_llvm_gen::generate(&pool, &resolution, &types, &mut out)?;
+    }
 
     Ok(())
 }
+
+/// Renders all collected diagnostics and exits with a failure status.
+fn fail(config: &Config, pool: &AST, diagnostics: &Diagnostics) -> io::Result<()> {
+    let mut err = io::stderr();
+
+    match config.error_format {
+        ErrorFormat::Human => {
+            diagnostics.render(pool, &mut err)?;
+
+            eprintln!(
+                "error: could not compile `{}` due to {} previous error(s)",
+                pool.path,
+                diagnostics.error_count()
+            );
+        }
+        ErrorFormat::Json => diagnostics.render_json(pool, &mut err)?,
+    }
+
+    process::exit(1);
+}
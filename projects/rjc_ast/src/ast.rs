@@ -3,9 +3,12 @@ use std::{fmt::Debug, fmt::Write, hash::Hash, io, marker::PhantomData};
 use dot::Dot;
 
 use crate::{
-    expression::{Cmp, Expression, ExpressionList, Literal, Sum},
-    statement::{Assignment, If, Return, Statement},
-    Block, Call, Function, Ident, Module, Variable,
+    expression::{
+        ArrayLiteral, ArrayRepeat, Bitwise, Cast, Cmp, Expression, ExpressionList, Field,
+        FieldValue, Index, Literal, Logical, Product, StructLiteral, Sum, Unary,
+    },
+    statement::{Assignment, Break, Continue, ElseIf, For, If, Return, Statement, While},
+    Block, Call, Function, Global, Ident, Module, Struct, Variable,
 };
 
 impl_nodes! {
@@ -15,22 +18,40 @@ impl_nodes! {
     Variable => Variable,
     Ident => Ident,
     Call => Call,
+    Struct => Struct,
+    Global => Global,
 
     // Statements
     Statement => Statement,
     Return => Return,
     Assignment => Assignment,
     If => If,
+    ElseIf => ElseIf,
+    While => While,
+    For => For,
+    Break => Break,
+    Continue => Continue,
 
     // Expressions
     Expression => Expression,
     ExpressionList => ExpressionList,
     Literal => Literal,
     Cmp => Cmp,
-    Sum => Sum
+    Sum => Sum,
+    Product => Product,
+    Logical => Logical,
+    Unary => Unary,
+    Bitwise => Bitwise,
+    Cast => Cast,
+    Field => Field,
+    StructLiteral => StructLiteral,
+    FieldValue => FieldValue,
+    Index => Index,
+    ArrayLiteral => ArrayLiteral,
+    ArrayRepeat => ArrayRepeat
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug)]
 pub struct ASTRef<T>
 where
     T: ASTType,
@@ -50,15 +71,26 @@ impl<T: ASTType> Dot for ASTRef<T> {
 
 impl<T: ASTType> Clone for ASTRef<T> {
     fn clone(&self) -> Self {
-        Self {
-            pool_id: self.pool_id,
-            _type: self._type,
-        }
+        *self
     }
 }
 
 impl<T: ASTType> Copy for ASTRef<T> {}
 
+impl<T: ASTType> PartialEq for ASTRef<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pool_id == other.pool_id
+    }
+}
+
+impl<T: ASTType> Eq for ASTRef<T> {}
+
+impl<T: ASTType> Hash for ASTRef<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.pool_id.hash(state);
+    }
+}
+
 pub trait ASTType: Debug + Sized + Hash {
     fn get(pool: &AST, pool_ref: ASTRef<Self>) -> &Self;
 
@@ -79,6 +111,7 @@ pub struct AST {
     pub path: String,
     pub input: String,
     data: Vec<ASTNode>,
+    module: Option<ASTRef<Module>>,
 }
 
 impl AST {
@@ -87,13 +120,26 @@ impl AST {
             path,
             input,
             data: Vec::new(),
+            module: None,
         }
     }
 
+    pub fn set_module(&mut self, module: ASTRef<Module>) {
+        self.module = Some(module);
+    }
+
+    pub fn module(&self) -> ASTRef<Module> {
+        self.module.expect("no module in AST")
+    }
+
     pub fn len(&self) -> usize {
         self.data.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
     pub fn graph(&self, output: &mut dyn io::Write) -> io::Result<()> {
         writeln!(output, "digraph {{")?;
 
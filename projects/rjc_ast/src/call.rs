@@ -4,11 +4,13 @@ use dot::DotLabel;
 
 use crate::expression::ExpressionList;
 
-use super::{Ident, ASTRef};
+use super::{Ident, ASTRef, Symbol};
 
 #[derive(Debug, Hash, Dot)]
 pub struct Call {
     pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
     #[dot_edge]
     pub ident: ASTRef<Ident>,
     #[dot_edge]
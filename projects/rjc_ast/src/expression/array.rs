@@ -0,0 +1,43 @@
+use std::fmt::Write as _;
+
+use dot::DotLabel;
+
+use crate::{expression::Expression, ASTRef, Symbol};
+
+/// An array of the listed elements, `[1, 2, 3]`.
+#[derive(Debug, Dot, Hash)]
+pub struct ArrayLiteral {
+    pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
+    #[dot_edge]
+    pub elements: Vec<ASTRef<Expression>>,
+}
+
+impl DotLabel for ArrayLiteral {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
+
+/// An array holding `length` copies of a value, `[0; 8]`.
+#[derive(Debug, Dot, Hash)]
+pub struct ArrayRepeat {
+    pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
+    #[dot_edge]
+    pub value: ASTRef<Expression>,
+    #[dot_display]
+    pub length: usize,
+}
+
+impl DotLabel for ArrayRepeat {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
@@ -0,0 +1,78 @@
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+};
+
+use dot::{Dot, DotLabel};
+
+use crate::{ASTRef, Symbol};
+
+use super::{sum::calculate_hash, Expression};
+
+/// A chain of bitwise operators of the same precedence: `|`, `^`, `&` or the
+/// shifts `<<` and `>>`.
+#[derive(Debug, Hash, Dot)]
+pub struct Bitwise {
+    pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
+    #[dot_edge]
+    pub first: ASTRef<Expression>,
+    #[dot_edge]
+    pub rest: Vec<BitwiseOp>,
+}
+
+#[derive(Debug, Hash)]
+pub enum BitwiseOp {
+    Or(ASTRef<Expression>),
+    Xor(ASTRef<Expression>),
+    And(ASTRef<Expression>),
+    Shl(ASTRef<Expression>),
+    Shr(ASTRef<Expression>),
+}
+
+impl BitwiseOp {
+    pub fn expression(&self) -> ASTRef<Expression> {
+        match self {
+            BitwiseOp::Or(expression)
+            | BitwiseOp::Xor(expression)
+            | BitwiseOp::And(expression)
+            | BitwiseOp::Shl(expression)
+            | BitwiseOp::Shr(expression) => *expression,
+        }
+    }
+}
+
+impl Dot for BitwiseOp {
+    fn dot(&self, output: &mut dyn Write) -> io::Result<String> {
+        let mut label = String::new();
+        write!(label, "ast_bitwise_{}", calculate_hash(self)).unwrap();
+
+        let name = match self {
+            BitwiseOp::Or(_) => "bit_or",
+            BitwiseOp::Xor(_) => "bit_xor",
+            BitwiseOp::And(_) => "bit_and",
+            BitwiseOp::Shl(_) => "shl",
+            BitwiseOp::Shr(_) => "shr",
+        };
+
+        let to_label = self.expression().dot(output)?;
+
+        writeln!(
+            output,
+            "{} [ label = \"{}\", shape = circle ];",
+            label, name
+        )?;
+        writeln!(output, "{} -> {};", label, to_label)?;
+
+        Ok(label)
+    }
+}
+
+impl DotLabel for Bitwise {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
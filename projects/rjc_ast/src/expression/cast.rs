@@ -0,0 +1,26 @@
+use std::fmt::Write as _;
+
+use dot::DotLabel;
+
+use crate::{expression::Expression, ASTRef, Symbol, Type};
+
+/// Conversion between number types, of a bool or char to an integer type, or
+/// of an integer or char to a char, `expression as type_`.
+#[derive(Debug, Hash, Dot)]
+pub struct Cast {
+    pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
+    #[dot_edge]
+    pub expression: ASTRef<Expression>,
+    #[dot_display]
+    pub type_: Type,
+}
+
+impl DotLabel for Cast {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
@@ -2,12 +2,14 @@ use std::fmt::{self, Display, Write as _};
 
 use dot::DotLabel;
 
-use crate::{expression::Expression, ASTRef};
+use crate::{expression::Expression, ASTRef, Symbol};
 
 #[derive(Debug, Hash, Dot)]
 pub struct Cmp {
     pub id: usize,
     #[dot_display]
+    pub symbol: Symbol,
+    #[dot_display]
     pub op: CmpOp,
     #[dot_edge]
     pub left: ASTRef<Expression>,
@@ -2,9 +2,12 @@ use std::fmt::Write as _;
 
 use dot::Dot;
 
-use crate::{Call, Ident, ASTRef};
+use crate::{Call, Ident, ASTRef, AST, Symbol};
 
-use super::{Cmp, Literal, Sum};
+use super::{
+    ArrayLiteral, ArrayRepeat, Bitwise, Cast, Cmp, Field, Index, Literal, Logical, Product,
+    StructLiteral, Sum, Unary,
+};
 
 #[derive(Debug, Hash)]
 pub enum Expression {
@@ -13,6 +16,39 @@ pub enum Expression {
     Call((ASTRef<Call>, usize)),
     Cmp((ASTRef<Cmp>, usize)),
     Sum((ASTRef<Sum>, usize)),
+    Product((ASTRef<Product>, usize)),
+    Logical((ASTRef<Logical>, usize)),
+    Unary((ASTRef<Unary>, usize)),
+    Bitwise((ASTRef<Bitwise>, usize)),
+    Cast((ASTRef<Cast>, usize)),
+    Field((ASTRef<Field>, usize)),
+    StructLiteral((ASTRef<StructLiteral>, usize)),
+    Index((ASTRef<Index>, usize)),
+    ArrayLiteral((ASTRef<ArrayLiteral>, usize)),
+    ArrayRepeat((ASTRef<ArrayRepeat>, usize)),
+}
+
+impl Expression {
+    /// The source location of the expression.
+    pub fn symbol<'a>(&self, pool: &'a AST) -> &'a Symbol {
+        match self {
+            Expression::Literal((node, _)) => &pool.get(*node).symbol,
+            Expression::Ident((node, _)) => &pool.get(*node).symbol,
+            Expression::Call((node, _)) => &pool.get(*node).symbol,
+            Expression::Cmp((node, _)) => &pool.get(*node).symbol,
+            Expression::Sum((node, _)) => &pool.get(*node).symbol,
+            Expression::Product((node, _)) => &pool.get(*node).symbol,
+            Expression::Logical((node, _)) => &pool.get(*node).symbol,
+            Expression::Unary((node, _)) => &pool.get(*node).symbol,
+            Expression::Bitwise((node, _)) => &pool.get(*node).symbol,
+            Expression::Cast((node, _)) => &pool.get(*node).symbol,
+            Expression::Field((node, _)) => &pool.get(*node).symbol,
+            Expression::StructLiteral((node, _)) => &pool.get(*node).symbol,
+            Expression::Index((node, _)) => &pool.get(*node).symbol,
+            Expression::ArrayLiteral((node, _)) => &pool.get(*node).symbol,
+            Expression::ArrayRepeat((node, _)) => &pool.get(*node).symbol,
+        }
+    }
 }
 
 impl Dot for Expression {
@@ -23,6 +59,16 @@ impl Dot for Expression {
             Expression::Call((node, id)) => (node.dot(output)?, *id),
             Expression::Cmp((node, id)) => (node.dot(output)?, *id),
             Expression::Sum((node, id)) => (node.dot(output)?, *id),
+            Expression::Product((node, id)) => (node.dot(output)?, *id),
+            Expression::Logical((node, id)) => (node.dot(output)?, *id),
+            Expression::Unary((node, id)) => (node.dot(output)?, *id),
+            Expression::Bitwise((node, id)) => (node.dot(output)?, *id),
+            Expression::Cast((node, id)) => (node.dot(output)?, *id),
+            Expression::Field((node, id)) => (node.dot(output)?, *id),
+            Expression::StructLiteral((node, id)) => (node.dot(output)?, *id),
+            Expression::Index((node, id)) => (node.dot(output)?, *id),
+            Expression::ArrayLiteral((node, id)) => (node.dot(output)?, *id),
+            Expression::ArrayRepeat((node, id)) => (node.dot(output)?, *id),
         };
 
         let mut label = String::new();
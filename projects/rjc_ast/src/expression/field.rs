@@ -0,0 +1,25 @@
+use std::fmt::Write as _;
+
+use dot::DotLabel;
+
+use crate::{expression::Expression, ASTRef, Ident, Symbol};
+
+/// Access of the field `field` of a struct value, `p.x`.
+#[derive(Debug, Dot, Hash)]
+pub struct Field {
+    pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
+    #[dot_edge]
+    pub expression: ASTRef<Expression>,
+    #[dot_edge]
+    pub field: ASTRef<Ident>,
+}
+
+impl DotLabel for Field {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
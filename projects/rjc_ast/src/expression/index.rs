@@ -0,0 +1,25 @@
+use std::fmt::Write as _;
+
+use dot::DotLabel;
+
+use crate::{expression::Expression, ASTRef, Symbol};
+
+/// Access of an array element, `a[i]`.
+#[derive(Debug, Dot, Hash)]
+pub struct Index {
+    pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
+    #[dot_edge]
+    pub expression: ASTRef<Expression>,
+    #[dot_edge]
+    pub index: ASTRef<Expression>,
+}
+
+impl DotLabel for Index {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
@@ -1,8 +1,11 @@
-use std::fmt::Write;
+use std::{
+    fmt::{self, Display, Write as _},
+    hash::{Hash, Hasher},
+};
 
 use dot::DotLabel;
 
-use crate::Symbol;
+use crate::{Symbol, Type};
 
 #[derive(Debug, Hash, Dot)]
 pub struct Literal {
@@ -10,7 +13,85 @@ pub struct Literal {
     #[dot_display]
     pub symbol: Symbol,
     #[dot_display]
-    pub value: String,
+    pub value: LiteralValue,
+}
+
+/// The value of a literal as parsed from the source. Numbers keep the type
+/// given by a suffix like in `10u8`, strings hold the text with escapes
+/// replaced.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LiteralValue {
+    Integer { value: i128, suffix: Option<Type> },
+    Float { value: f64, suffix: Option<Type> },
+    Bool(bool),
+    Char(char),
+    Str(String),
+}
+
+impl Literal {
+    pub fn suffix(&self) -> Option<&Type> {
+        match &self.value {
+            LiteralValue::Integer { suffix, .. } | LiteralValue::Float { suffix, .. } => {
+                suffix.as_ref()
+            }
+            LiteralValue::Bool(_) | LiteralValue::Char(_) | LiteralValue::Str(_) => None,
+        }
+    }
+}
+
+impl Hash for LiteralValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            LiteralValue::Integer { value, suffix } => {
+                value.hash(state);
+                suffix.hash(state);
+            }
+            LiteralValue::Float { value, suffix } => {
+                value.to_bits().hash(state);
+                suffix.hash(state);
+            }
+            LiteralValue::Bool(value) => value.hash(state),
+            LiteralValue::Char(value) => value.hash(state),
+            LiteralValue::Str(value) => value.hash(state),
+        }
+    }
+}
+
+impl Display for LiteralValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let suffix = match self {
+            LiteralValue::Integer { value, suffix } => {
+                write!(f, "{}", value)?;
+                suffix
+            }
+            LiteralValue::Float { value, suffix } => {
+                write!(f, "{:?}", value)?;
+                suffix
+            }
+            LiteralValue::Bool(value) => return write!(f, "{}", value),
+            LiteralValue::Char(value) => return write!(f, "'{}'", value.escape_default()),
+            LiteralValue::Str(value) => {
+                // Shown in dot record labels, where quotes, braces, bars
+                // and angle brackets have a meaning of their own.
+                write!(f, "\\\"")?;
+
+                for c in value.escape_default() {
+                    if "{}|<>".contains(c) {
+                        write!(f, "\\")?;
+                    }
+
+                    write!(f, "{}", c)?;
+                }
+
+                return write!(f, "\\\"");
+            }
+        };
+
+        match suffix {
+            Some(suffix) => write!(f, "{}", suffix),
+            None => Ok(()),
+        }
+    }
 }
 
 impl DotLabel for Literal {
@@ -0,0 +1,56 @@
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+};
+
+use dot::{Dot, DotLabel};
+
+use crate::{ASTRef, Symbol};
+
+use super::{sum::calculate_hash, Expression};
+
+/// A chain of `&&` or `||` operators, the right hand side of each operator is
+/// only evaluated if the left hand side does not decide the result.
+#[derive(Debug, Hash, Dot)]
+pub struct Logical {
+    pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
+    #[dot_edge]
+    pub first: ASTRef<Expression>,
+    #[dot_edge]
+    pub rest: Vec<LogicalOp>,
+}
+
+#[derive(Debug, Hash)]
+pub enum LogicalOp {
+    And(ASTRef<Expression>),
+    Or(ASTRef<Expression>),
+}
+
+impl Dot for LogicalOp {
+    fn dot(&self, output: &mut dyn Write) -> io::Result<String> {
+        let mut label = String::new();
+        write!(label, "ast_logical_{}", calculate_hash(self)).unwrap();
+
+        let (name, expression) = match self {
+            LogicalOp::And(expression) => ("and", expression),
+            LogicalOp::Or(expression) => ("or", expression),
+        };
+
+        let to_label = expression.dot(output)?;
+
+        writeln!(output, "{} [ label = \"{}\", shape = circle ];", label, name)?;
+        writeln!(output, "{} -> {};", label, to_label)?;
+
+        Ok(label)
+    }
+}
+
+impl DotLabel for Logical {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
@@ -1,11 +1,30 @@
+mod array;
+mod bitwise;
+mod cast;
 mod cmp;
+#[allow(clippy::module_inception)]
 mod expression;
+mod field;
+mod index;
 mod list;
 mod literal;
+mod logical;
+mod product;
+mod struct_literal;
 mod sum;
+mod unary;
 
+pub use array::*;
+pub use bitwise::*;
+pub use cast::*;
 pub use cmp::*;
 pub use expression::*;
+pub use field::*;
+pub use index::*;
 pub use list::*;
 pub use literal::*;
+pub use logical::*;
+pub use product::*;
+pub use struct_literal::*;
 pub use sum::*;
+pub use unary::*;
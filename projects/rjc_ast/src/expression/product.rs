@@ -0,0 +1,56 @@
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+};
+
+use dot::{Dot, DotLabel};
+
+use crate::{ASTRef, Symbol};
+
+use super::{sum::calculate_hash, Expression};
+
+#[derive(Debug, Hash, Dot)]
+pub struct Product {
+    pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
+    #[dot_edge]
+    pub first: ASTRef<Expression>,
+    #[dot_edge]
+    pub rest: Vec<ProductOp>,
+}
+
+#[derive(Debug, Hash)]
+pub enum ProductOp {
+    Mul(ASTRef<Expression>),
+    Div(ASTRef<Expression>),
+    Rem(ASTRef<Expression>),
+}
+
+impl Dot for ProductOp {
+    fn dot(&self, output: &mut dyn Write) -> io::Result<String> {
+        let mut label = String::new();
+        write!(label, "ast_product_{}", calculate_hash(self)).unwrap();
+
+        let (name, expression) = match self {
+            ProductOp::Mul(expression) => ("mul", expression),
+            ProductOp::Div(expression) => ("div", expression),
+            ProductOp::Rem(expression) => ("rem", expression),
+        };
+
+        let to_label = expression.dot(output)?;
+
+        writeln!(output, "{} [ label = \"{}\", shape = circle ];", label, name)?;
+        writeln!(output, "{} -> {};", label, to_label)?;
+
+        Ok(label)
+    }
+}
+
+impl DotLabel for Product {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
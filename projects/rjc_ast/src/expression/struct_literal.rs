@@ -0,0 +1,45 @@
+use std::fmt::Write as _;
+
+use dot::DotLabel;
+
+use crate::{expression::Expression, ASTRef, Ident, Symbol};
+
+/// A struct value built from a value for every field, `Point { x: 1, y: 2 }`.
+#[derive(Debug, Dot, Hash)]
+pub struct StructLiteral {
+    pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
+    #[dot_edge]
+    pub ident: ASTRef<Ident>,
+    #[dot_edge]
+    pub fields: Vec<ASTRef<FieldValue>>,
+}
+
+impl DotLabel for StructLiteral {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
+
+/// The value of one field in a `StructLiteral`.
+#[derive(Debug, Dot, Hash)]
+pub struct FieldValue {
+    pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
+    #[dot_edge]
+    pub ident: ASTRef<Ident>,
+    #[dot_edge]
+    pub expression: ASTRef<Expression>,
+}
+
+impl DotLabel for FieldValue {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
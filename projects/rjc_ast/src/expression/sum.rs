@@ -7,13 +7,15 @@ use std::{
 
 use dot::{Dot, DotLabel};
 
-use crate::ASTRef;
+use crate::{ASTRef, Symbol};
 
 use super::Expression;
 
 #[derive(Debug, Hash, Dot)]
 pub struct Sum {
     pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
     #[dot_edge]
     pub first: ASTRef<Expression>,
     #[dot_edge]
@@ -58,7 +60,7 @@ impl DotLabel for Sum {
     }
 }
 
-fn calculate_hash<T: Hash>(t: &T) -> u64 {
+pub(super) fn calculate_hash<T: Hash>(t: &T) -> u64 {
     let mut s = DefaultHasher::new();
     t.hash(&mut s);
     s.finish()
@@ -0,0 +1,43 @@
+use std::fmt::{self, Display, Write as _};
+
+use dot::DotLabel;
+
+use crate::{expression::Expression, ASTRef, Symbol};
+
+#[derive(Debug, Hash, Dot)]
+pub struct Unary {
+    pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
+    #[dot_display]
+    pub op: UnaryOp,
+    #[dot_edge]
+    pub expression: ASTRef<Expression>,
+}
+
+#[derive(Debug, Hash)]
+pub enum UnaryOp {
+    Not,
+    Neg,
+    BitNot,
+}
+
+impl Display for UnaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        let op = match self {
+            UnaryOp::Not => "not",
+            UnaryOp::Neg => "neg",
+            UnaryOp::BitNot => "bit_not",
+        };
+
+        write!(f, "{}", op)
+    }
+}
+
+impl DotLabel for Unary {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
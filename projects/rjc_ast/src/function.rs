@@ -17,6 +17,9 @@ pub struct Function {
     pub block: ASTRef<Block>,
     #[dot_display]
     pub return_type: TypeList,
+    /// Lines of the `///` comments in front of the function, without the
+    /// leading slashes.
+    pub doc: Vec<String>,
 }
 
 impl DotLabel for Function {
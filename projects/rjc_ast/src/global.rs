@@ -0,0 +1,29 @@
+use std::fmt::Write as _;
+
+use dot::DotLabel;
+
+use crate::{expression::Expression, ASTRef, Symbol, Variable};
+
+/// A module-level `const` or `var`. Both live for the whole run of the
+/// program and are initialized with a value known at compile time, only a
+/// `var` can be assigned to.
+#[derive(Debug, Dot, Hash)]
+pub struct Global {
+    pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
+    #[dot_display]
+    pub constant: bool,
+    #[dot_edge]
+    pub variable: ASTRef<Variable>,
+    #[dot_edge]
+    pub value: ASTRef<Expression>,
+}
+
+impl DotLabel for Global {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
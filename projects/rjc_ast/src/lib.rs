@@ -13,6 +13,8 @@ mod types;
 mod variable;
 mod ident;
 mod call;
+mod struct_;
+mod global;
 
 pub use block::*;
 pub use function::*;
@@ -23,6 +25,8 @@ pub use types::*;
 pub use variable::*;
 pub use ident::*;
 pub use call::*;
+pub use struct_::*;
+pub use global::*;
 
 pub mod expression;
 pub mod statement;
\ No newline at end of file
@@ -2,12 +2,16 @@ use std::fmt::Write as _;
 
 use dot::DotLabel;
 
-use crate::{Function, ASTRef};
+use crate::{Function, ASTRef, Global, Struct};
 
 #[derive(Debug, Dot, Hash)]
 pub struct Module {
     pub id: usize,
     #[dot_edge]
+    pub structs: Vec<ASTRef<Struct>>,
+    #[dot_edge]
+    pub globals: Vec<ASTRef<Global>>,
+    #[dot_edge]
     pub functions: Vec<ASTRef<Function>>,
 }
 
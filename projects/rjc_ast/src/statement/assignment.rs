@@ -2,13 +2,17 @@ use std::fmt::Write as _;
 
 use dot::DotLabel;
 
-use crate::{expression::ExpressionList, Ident, ASTRef};
+use crate::{
+    expression::{Expression, ExpressionList},
+    ASTRef,
+};
 
 #[derive(Debug, Dot, Hash)]
 pub struct Assignment {
     pub id: usize,
+    /// Variables, fields or elements of variables, `a`, `p.x` or `a[i]`.
     #[dot_edge]
-    pub targets: Vec<ASTRef<Ident>>,
+    pub targets: Vec<ASTRef<Expression>>,
     #[dot_edge]
     pub expressions: ASTRef<ExpressionList>,
 }
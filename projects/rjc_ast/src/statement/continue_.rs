@@ -0,0 +1,20 @@
+use std::fmt::Write as _;
+
+use dot::DotLabel;
+
+use crate::Symbol;
+
+#[derive(Debug, Dot, Hash)]
+pub struct Continue {
+    pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
+}
+
+impl DotLabel for Continue {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt::Write as _;
+
+use dot::DotLabel;
+
+use crate::{expression::Expression, ASTRef, Block, Variable};
+
+/// `for variable in start..end` or, if `inclusive`, `start..=end`.
+#[derive(Debug, Dot, Hash)]
+pub struct For {
+    pub id: usize,
+    #[dot_edge]
+    pub variable: ASTRef<Variable>,
+    #[dot_edge]
+    pub start: ASTRef<Expression>,
+    #[dot_edge]
+    pub end: ASTRef<Expression>,
+    #[dot_display]
+    pub inclusive: bool,
+    #[dot_edge]
+    pub block: ASTRef<Block>,
+}
+
+impl DotLabel for For {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
@@ -12,6 +12,8 @@ pub struct If {
     #[dot_edge]
     pub if_block: ASTRef<Block>,
     #[dot_edge]
+    pub else_ifs: Vec<ASTRef<ElseIf>>,
+    #[dot_edge]
     pub else_block: Option<ASTRef<Block>>,
 }
 
@@ -22,3 +24,22 @@ impl DotLabel for If {
         label
     }
 }
+
+/// An `else if` arm of an `If`, tried in order when all previous conditions
+/// were false.
+#[derive(Debug, Dot, Hash)]
+pub struct ElseIf {
+    pub id: usize,
+    #[dot_edge]
+    pub condition: ASTRef<Expression>,
+    #[dot_edge]
+    pub block: ASTRef<Block>,
+}
+
+impl DotLabel for ElseIf {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
@@ -1,9 +1,18 @@
 mod assignment;
+mod break_;
+mod continue_;
+mod for_;
 mod if_;
 mod return_;
+#[allow(clippy::module_inception)]
 mod statement;
+mod while_;
 
 pub use assignment::*;
+pub use break_::*;
+pub use continue_::*;
+pub use for_::*;
 pub use if_::*;
 pub use return_::*;
 pub use statement::*;
+pub use while_::*;
@@ -4,7 +4,7 @@ use dot::Dot;
 
 use crate::{ASTRef, Variable, Call};
 
-use super::{Assignment, If, Return};
+use super::{Assignment, Break, Continue, For, If, Return, While};
 
 #[derive(Debug, Hash)]
 pub enum Statement {
@@ -13,6 +13,10 @@ pub enum Statement {
     Assignment((ASTRef<Assignment>, usize)),
     If((ASTRef<If>, usize)),
     Return((ASTRef<Return>, usize)),
+    While((ASTRef<While>, usize)),
+    For((ASTRef<For>, usize)),
+    Break((ASTRef<Break>, usize)),
+    Continue((ASTRef<Continue>, usize)),
 }
 
 impl Dot for Statement {
@@ -23,6 +27,10 @@ impl Dot for Statement {
             Statement::If((node, id)) => (id, node.dot(output)?),
             Statement::Return((node, id)) => (id, node.dot(output)?),
             Statement::Call((node, id)) => (id, node.dot(output)?),
+            Statement::While((node, id)) => (id, node.dot(output)?),
+            Statement::For((node, id)) => (id, node.dot(output)?),
+            Statement::Break((node, id)) => (id, node.dot(output)?),
+            Statement::Continue((node, id)) => (id, node.dot(output)?),
         };
 
         let mut label = String::new();
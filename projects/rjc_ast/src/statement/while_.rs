@@ -0,0 +1,22 @@
+use std::fmt::Write as _;
+
+use dot::DotLabel;
+
+use crate::{expression::Expression, ASTRef, Block};
+
+#[derive(Debug, Dot, Hash)]
+pub struct While {
+    pub id: usize,
+    #[dot_edge]
+    pub condition: ASTRef<Expression>,
+    #[dot_edge]
+    pub block: ASTRef<Block>,
+}
+
+impl DotLabel for While {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt::Write as _;
+
+use dot::DotLabel;
+
+use crate::{ASTRef, Ident, Symbol, Variable, AST};
+
+/// A struct declaration, its fields are laid out in declaration order.
+#[derive(Debug, Dot, Hash)]
+pub struct Struct {
+    pub id: usize,
+    #[dot_display]
+    pub symbol: Symbol,
+    #[dot_edge]
+    pub ident: ASTRef<Ident>,
+    #[dot_edge]
+    pub fields: Vec<ASTRef<Variable>>,
+}
+
+impl Struct {
+    /// The position and declaration of the field called `name`.
+    pub fn field<'a>(&self, pool: &'a AST, name: &str) -> Option<(usize, &'a Variable)> {
+        self.fields
+            .iter()
+            .map(|field| pool.get(*field))
+            .enumerate()
+            .find(|(_, field)| pool.get(field.name).name == name)
+    }
+}
+
+impl DotLabel for Struct {
+    fn dot_label(&self) -> String {
+        let mut label = String::new();
+        write!(label, "ast_node_{}", self.id).unwrap();
+        label
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Clone, Hash)]
 pub struct Symbol {
     pub line: usize,
     pub column: usize,
@@ -1,9 +1,28 @@
 use std::fmt::{Display, Debug};
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Type {
     Unknown,
+    I8,
+    I16,
     I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    USize,
+    F32,
+    F64,
+    Bool,
+    /// A Unicode scalar value.
+    Char,
+    /// Immutable UTF-8 text, a pointer to the bytes and their length.
+    Str,
+    /// A struct declared in the module, by name.
+    Struct(String),
+    /// A fixed number of elements of the same type, `[i32; 8]`.
+    Array(Box<Type>, usize),
 }
 
 #[derive(Hash)]
@@ -12,25 +31,107 @@ pub struct TypeList {
 }
 
 impl Type {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(_type: &'_ str) -> Type {
         match _type {
+            "i8" => Type::I8,
+            "i16" => Type::I16,
             "i32" => Type::I32,
+            "i64" => Type::I64,
+            "u8" => Type::U8,
+            "u16" => Type::U16,
+            "u32" => Type::U32,
+            "u64" => Type::U64,
+            "usize" => Type::USize,
+            "f32" => Type::F32,
+            "f64" => Type::F64,
+            "bool" => Type::Bool,
+            "char" => Type::Char,
+            "str" => Type::Str,
 
-            _ => panic!("invalid type string"),
+            // The parser only passes in `var_type`, `int_suffix` and
+            // `float_suffix` pairs, which match exactly the names above.
+            _ => unreachable!("invalid type string `{}`", _type),
         }
     }
 
-    pub fn as_str(&self) -> &str {
+    pub fn is_integer(&self) -> bool {
+        self.integer_bits().is_some()
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self, Type::F32 | Type::F64)
+    }
+
+    /// Integers and floats.
+    pub fn is_number(&self) -> bool {
+        self.is_integer() || self.is_float()
+    }
+
+    /// Numbers, bools and chars, which unlike structs can be compared.
+    pub fn is_scalar(&self) -> bool {
+        self.is_number() || matches!(self, Type::Bool | Type::Char)
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(self, Type::I8 | Type::I16 | Type::I32 | Type::I64)
+    }
+
+    /// Width of an integer type in bits, `usize` is 64 bits wide as only
+    /// 64 bit targets are supported.
+    pub fn integer_bits(&self) -> Option<u32> {
         match self {
-            Type::Unknown => "unknown",
-            Type::I32 => "i32",
+            Type::I8 | Type::U8 => Some(8),
+            Type::I16 | Type::U16 => Some(16),
+            Type::I32 | Type::U32 => Some(32),
+            Type::I64 | Type::U64 | Type::USize => Some(64),
+
+            Type::Unknown
+            | Type::F32
+            | Type::F64
+            | Type::Bool
+            | Type::Char
+            | Type::Str
+            | Type::Struct(_)
+            | Type::Array(_, _) => None,
+        }
+    }
+
+    /// Smallest and largest value of an integer type.
+    pub fn integer_range(&self) -> Option<(i128, i128)> {
+        let bits = self.integer_bits()?;
+
+        if self.is_signed() {
+            Some((-(1 << (bits - 1)), (1 << (bits - 1)) - 1))
+        } else {
+            Some((0, (1 << bits) - 1))
         }
     }
 }
 
 impl Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.as_str())
+        let name = match self {
+            Type::Unknown => "unknown",
+            Type::I8 => "i8",
+            Type::I16 => "i16",
+            Type::I32 => "i32",
+            Type::I64 => "i64",
+            Type::U8 => "u8",
+            Type::U16 => "u16",
+            Type::U32 => "u32",
+            Type::U64 => "u64",
+            Type::USize => "usize",
+            Type::F32 => "f32",
+            Type::F64 => "f64",
+            Type::Bool => "bool",
+            Type::Char => "char",
+            Type::Str => "str",
+            Type::Struct(name) => name,
+            Type::Array(element, length) => return write!(f, "[{}; {}]", element, length),
+        };
+
+        write!(f, "{}", name)
     }
 }
 
@@ -41,7 +142,7 @@ impl Display for TypeList {
                 write!(f, ", ")?;
             }
 
-            write!(f, "{}", item.as_str())?;
+            write!(f, "{}", item)?;
         }
 
         Ok(())
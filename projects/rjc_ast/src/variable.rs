@@ -4,6 +4,9 @@ use dot::DotLabel;
 
 use super::*;
 
+/// A local variable, argument, struct field or the variable of a global.
+/// Only local variables may leave out their type, which is then inferred
+/// from their initial value by the typer.
 #[derive(Debug, Dot, Hash)]
 pub struct Variable {
     pub id: usize,
@@ -11,7 +14,9 @@ pub struct Variable {
     pub symbol: Symbol,
     #[dot_edge]
     pub name: ASTRef<Ident>,
-    pub type_: Type,
+    pub type_: Option<Type>,
+    #[dot_edge]
+    pub value: Option<ASTRef<expression::Expression>>,
 }
 
 impl DotLabel for Variable {
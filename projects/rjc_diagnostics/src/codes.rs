@@ -0,0 +1,70 @@
+//! Error codes of all diagnostics reported by the compiler.
+//!
+//! Codes are stable: a code is never reused for a different kind of
+//! diagnostic. `E00xx` are reported by the parser, `E01xx` by the resolver
+//! and `E02xx` by the typer.
+
+/// The source does not match the grammar.
+pub const SYNTAX_ERROR: &str = "E0001";
+/// A string or character literal contains an unknown escape sequence.
+pub const INVALID_ESCAPE: &str = "E0002";
+/// A character literal is empty or holds more than one character.
+pub const INVALID_CHAR_LITERAL: &str = "E0003";
+/// An integer literal has no digits or a digit that is invalid for its base.
+pub const MALFORMED_NUMBER: &str = "E0004";
+
+/// An identifier used as a variable has no declaration in scope.
+pub const UNDECLARED_VARIABLE: &str = "E0101";
+/// A called function does not exist.
+pub const UNDECLARED_FUNCTION: &str = "E0102";
+/// A name is declared twice in the same scope.
+pub const DUPLICATE_DECLARATION: &str = "E0103";
+/// A function name is used where a variable is expected.
+pub const NOT_A_VARIABLE: &str = "E0104";
+/// A variable name is called like a function.
+pub const NOT_A_FUNCTION: &str = "E0105";
+/// `break` or `continue` is used outside of a loop.
+pub const OUTSIDE_OF_LOOP: &str = "E0106";
+/// A type name does not refer to a builtin type or a declared struct.
+pub const UNDECLARED_TYPE: &str = "E0107";
+/// A struct contains itself, directly or through other structs.
+pub const RECURSIVE_TYPE: &str = "E0108";
+/// A `const` is assigned to.
+pub const ASSIGNMENT_TO_CONSTANT: &str = "E0109";
+/// The module has no `main` function.
+pub const MISSING_MAIN: &str = "E0110";
+
+/// An expression does not have the type required by its context.
+pub const MISMATCHED_TYPES: &str = "E0201";
+/// A call supplies a different number of arguments than the function takes.
+pub const ARGUMENT_COUNT: &str = "E0202";
+/// An assignment has a different number of values than targets.
+pub const ASSIGNMENT_COUNT: &str = "E0203";
+/// A return statement returns a different number of values than declared.
+pub const RETURN_COUNT: &str = "E0204";
+/// A call used as a single value returns no or several values.
+pub const NOT_A_SINGLE_VALUE: &str = "E0205";
+/// An operator is applied to a type it is not defined for.
+pub const INVALID_OPERAND: &str = "E0206";
+/// A division or remainder has the literal `0` as divisor.
+pub const DIVISION_BY_ZERO: &str = "E0207";
+/// An integer literal does not fit into its type.
+pub const LITERAL_OUT_OF_RANGE: &str = "E0208";
+/// A cast between types that cannot be converted into each other.
+pub const INVALID_CAST: &str = "E0209";
+/// A field is accessed or initialized that the struct does not have.
+pub const UNKNOWN_FIELD: &str = "E0210";
+/// A struct literal does not initialize every field.
+pub const MISSING_FIELD: &str = "E0211";
+/// A struct literal initializes a field more than once.
+pub const DUPLICATE_FIELD: &str = "E0212";
+/// An array is indexed with a constant that is not below its length.
+pub const INDEX_OUT_OF_BOUNDS: &str = "E0213";
+/// The value of a global is not known at compile time.
+pub const NOT_CONSTANT: &str = "E0214";
+/// A constant depends on its own value.
+pub const CYCLIC_CONSTANT: &str = "E0215";
+/// `main` takes arguments or does not return a single `i32`.
+pub const INVALID_MAIN: &str = "E0216";
+/// A function with return types can reach the end of its body.
+pub const MISSING_RETURN: &str = "E0217";
@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+use rjc_ast::Symbol;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Note => "note",
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A span of source code, optionally with a message shown next to it.
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub symbol: Symbol,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: &'static str,
+    pub message: String,
+    pub primary: Label,
+    pub labels: Vec<Label>,
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, code: &'static str, message: String, symbol: &Symbol) -> Self {
+        Diagnostic {
+            severity,
+            code,
+            message,
+            primary: Label {
+                symbol: symbol.clone(),
+                message: String::new(),
+            },
+            labels: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn error(code: &'static str, message: String, symbol: &Symbol) -> Self {
+        Self::new(Severity::Error, code, message, symbol)
+    }
+
+    pub fn warning(code: &'static str, message: String, symbol: &Symbol) -> Self {
+        Self::new(Severity::Warning, code, message, symbol)
+    }
+
+    /// Sets the message shown under the primary span.
+    pub fn with_message(mut self, message: String) -> Self {
+        self.primary.message = message;
+        self
+    }
+
+    /// Adds a secondary span, e.g. pointing at a previous declaration.
+    pub fn with_label(mut self, symbol: &Symbol, message: String) -> Self {
+        self.labels.push(Label {
+            symbol: symbol.clone(),
+            message,
+        });
+        self
+    }
+
+    pub fn with_note(mut self, note: String) -> Self {
+        self.notes.push(note);
+        self
+    }
+}
@@ -0,0 +1,48 @@
+use std::io;
+
+use rjc_ast::AST;
+
+use crate::{Diagnostic, Severity};
+
+/// Collects the diagnostics of all compiler passes of a run.
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    list: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    pub fn push(&mut self, diagnostic: Diagnostic) {
+        self.list.push(diagnostic);
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.list.iter()
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.iter()
+            .filter(|diagnostic| diagnostic.severity == Severity::Error)
+            .count()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.error_count() != 0
+    }
+
+    pub fn render(&self, pool: &AST, output: &mut dyn io::Write) -> io::Result<()> {
+        for diagnostic in self.iter() {
+            diagnostic.render(pool, output)?;
+            writeln!(output)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use std::{fmt::Write as _, io};
+
+use rjc_ast::AST;
+
+use crate::{Diagnostic, Diagnostics, Label};
+
+impl Diagnostic {
+    /// Writes the diagnostic as a single line JSON object, see the README for
+    /// the schema.
+    pub fn render_json(&self, pool: &AST, output: &mut dyn io::Write) -> io::Result<()> {
+        let mut json = String::new();
+
+        write!(json, "{{").unwrap();
+        write!(json, "\"file\":{},", string(&pool.path)).unwrap();
+        write!(json, "\"severity\":{},", string(self.severity.as_str())).unwrap();
+        write!(json, "\"code\":{},", string(self.code)).unwrap();
+        write!(json, "\"message\":{},", string(&self.message)).unwrap();
+        write!(json, "\"span\":{},", span(pool, &self.primary)).unwrap();
+
+        let related: Vec<String> = self.labels.iter().map(|label| span(pool, label)).collect();
+        write!(json, "\"related\":[{}],", related.join(",")).unwrap();
+
+        let notes: Vec<String> = self.notes.iter().map(|note| string(note)).collect();
+        write!(json, "\"notes\":[{}]", notes.join(",")).unwrap();
+        write!(json, "}}").unwrap();
+
+        writeln!(output, "{}", json)
+    }
+}
+
+impl Diagnostics {
+    pub fn render_json(&self, pool: &AST, output: &mut dyn io::Write) -> io::Result<()> {
+        for diagnostic in self.iter() {
+            diagnostic.render_json(pool, output)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn span(pool: &AST, label: &Label) -> String {
+    let symbol = &label.symbol;
+
+    format!(
+        "{{\"file\":{},\"line\":{},\"column\":{},\"start\":{},\"end\":{},\"label\":{}}}",
+        string(&pool.path),
+        symbol.line,
+        symbol.column,
+        symbol.start,
+        symbol.end,
+        string(&label.message)
+    )
+}
+
+/// Quotes and escapes a string as a JSON string literal.
+fn string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+
+    json.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+
+    json
+}
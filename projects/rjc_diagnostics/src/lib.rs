@@ -0,0 +1,9 @@
+mod diagnostic;
+mod diagnostics;
+mod json;
+mod render;
+
+pub mod codes;
+
+pub use diagnostic::*;
+pub use diagnostics::*;
@@ -0,0 +1,100 @@
+use std::io;
+
+use rjc_ast::AST;
+
+use crate::{Diagnostic, Label};
+
+impl Diagnostic {
+    /// Renders the diagnostic compiler-style, with every labeled span shown
+    /// in its source lines and underlined.
+    pub fn render(&self, pool: &AST, output: &mut dyn io::Write) -> io::Result<()> {
+        let mut labels: Vec<(&Label, char)> = vec![(&self.primary, '^')];
+        labels.extend(self.labels.iter().map(|label| (label, '-')));
+        labels.sort_by_key(|(label, _)| label.symbol.start);
+
+        let last_line = labels
+            .iter()
+            .map(|(label, _)| end_line(pool, label))
+            .max()
+            .unwrap_or(self.primary.symbol.line);
+        let gutter = " ".repeat(last_line.to_string().len());
+
+        writeln!(output, "{}[{}]: {}", self.severity, self.code, self.message)?;
+        writeln!(output, "{}--> {}:{}", gutter, pool.path, self.primary.symbol)?;
+        writeln!(output, "{} |", gutter)?;
+
+        let lines: Vec<&str> = pool.input.split('\n').collect();
+        let mut printed = 0;
+
+        for (label, mark) in labels {
+            let first = label.symbol.line;
+            let last = end_line(pool, label);
+
+            if printed != 0 && first > printed + 1 {
+                writeln!(output, "...")?;
+            }
+
+            for line in first..=last {
+                let text = lines.get(line - 1).copied().unwrap_or("");
+
+                if line > printed {
+                    writeln!(output, "{:>width$} | {}", line, text, width = gutter.len())?;
+                    printed = line;
+                }
+
+                // Character columns of the span on this line
+                let start = match line == first {
+                    true => label.symbol.column - 1,
+                    false => text.len() - text.trim_start().len(),
+                };
+                let end = match line == last {
+                    true => start + span_width(pool, label, line == first),
+                    false => text.chars().count(),
+                };
+
+                let mut underline = format!(
+                    "{} | {}{}",
+                    gutter,
+                    " ".repeat(start),
+                    mark.to_string().repeat(end.saturating_sub(start).max(1))
+                );
+
+                if line == last && !label.message.is_empty() {
+                    underline.push(' ');
+                    underline.push_str(&label.message);
+                }
+
+                writeln!(output, "{}", underline)?;
+            }
+        }
+
+        writeln!(output, "{} |", gutter)?;
+
+        for note in &self.notes {
+            writeln!(output, "{} = note: {}", gutter, note)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The line the span of a label ends on.
+fn end_line(pool: &AST, label: &Label) -> usize {
+    let span = &pool.input[label.symbol.start..label.symbol.end.min(pool.input.len())];
+
+    label.symbol.line + span.trim_end().matches('\n').count()
+}
+
+/// Width in characters of the part of the span on its last line.
+fn span_width(pool: &AST, label: &Label, single_line: bool) -> usize {
+    let span = &pool.input[label.symbol.start..label.symbol.end.min(pool.input.len())];
+    let span = span.trim_end();
+
+    match single_line {
+        true => span.chars().count(),
+        false => {
+            let last = span.rsplit('\n').next().unwrap_or("");
+            last.chars().count() - (last.len() - last.trim_start().len())
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use std::io;
+
+use rjc_ast::{Block, AST};
+
+use crate::{context::Context, IRGen};
+
+impl IRGen for Block {
+    type Output = ();
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        for statement in &self.statements {
+            // Code following a return still needs a basic block to live in.
+            if context.terminated {
+                let label = context.unique("dead");
+                context.label(output, &label)?;
+            }
+
+            pool.get(*statement).ir(pool, output, context)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use std::io;
+
+use rjc_ast::{Call, AST};
+
+use crate::{
+    context::{Context, Value},
+    function::mangle,
+    IRGen, LLVMType,
+};
+
+impl IRGen for Call {
+    type Output = Vec<Value>;
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let function = context
+            .resolution
+            .function(self.ident)
+            .expect("unresolved function in code generation");
+        let function = pool.get(function);
+        let name = &pool.get(function.ident).name;
+        let arguments = pool.get(self.expressions).ir(pool, output, context)?;
+
+        let mut pointers = vec![];
+        let mut params = vec![];
+
+        for type_ in &function.return_type.list {
+            let pointer = context.alloca("call", type_);
+
+            params.push(format!("{}* {}", type_.llvm_type(), pointer));
+            pointers.push(Value {
+                repr: pointer,
+                type_: type_.clone(),
+            });
+        }
+
+        for argument in arguments {
+            params.push(format!("{} {}", argument.type_.llvm_value_type(), argument.repr));
+        }
+
+        writeln!(output, "  call void {}({})", mangle(name), params.join(", "))?;
+
+        let mut values = vec![];
+
+        for pointer in pointers {
+            // The return slot of a value kept in memory is its own copy.
+            match pointer.type_.in_memory() {
+                true => values.push(pointer),
+                false => values.push(context.load(output, &pointer)?),
+            }
+        }
+
+        Ok(values)
+    }
+}
@@ -0,0 +1,231 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    io,
+};
+
+use rjc_ast::{ASTRef, Ident, Type, Variable};
+use rjc_resolver::Resolution;
+use rjc_typer::Types;
+
+use crate::LLVMType;
+
+/// A value produced by code generation, either a register or a constant.
+/// Values of types kept in memory are a pointer to a copy of their own,
+/// which is never written to once the value is produced.
+#[derive(Debug, Clone)]
+pub struct Value {
+    pub repr: String,
+    pub type_: Type,
+}
+
+/// Branch targets of an enclosing loop.
+pub struct Loop {
+    pub continue_label: String,
+    pub break_label: String,
+}
+
+pub struct Context<'a> {
+    unique: usize,
+    variables: HashMap<ASTRef<Variable>, Value>,
+    allocas: Vec<String>,
+    loops: Vec<Loop>,
+    intrinsics: BTreeSet<String>,
+    globals: Vec<String>,
+    pub resolution: &'a Resolution,
+    pub types: &'a Types,
+    pub terminated: bool,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(resolution: &'a Resolution, types: &'a Types) -> Context<'a> {
+        Context {
+            unique: 0,
+            variables: HashMap::new(),
+            allocas: Vec::new(),
+            loops: Vec::new(),
+            intrinsics: BTreeSet::new(),
+            globals: Vec::new(),
+            resolution,
+            types,
+            terminated: false,
+        }
+    }
+
+    pub fn unique(&mut self, prefix: &str) -> String {
+        self.unique += 1;
+
+        format!("{}.{}", prefix, self.unique)
+    }
+
+    pub fn register(&mut self) -> String {
+        format!("%{}", self.unique("r"))
+    }
+
+    /// Reserves stack space in the entry block of the current function and
+    /// returns the pointer to it.
+    pub fn alloca(&mut self, name: &str, type_: &Type) -> String {
+        let pointer = format!("%{}", self.unique(name));
+
+        self.allocas
+            .push(format!("  {} = alloca {}", pointer, type_.llvm_type()));
+
+        pointer
+    }
+
+    /// Reads the value `pointer` points to. Types kept in memory are copied
+    /// to a new stack slot, so later writes through `pointer` do not change
+    /// the value.
+    pub fn load(&mut self, output: &mut dyn io::Write, pointer: &Value) -> io::Result<Value> {
+        let repr = match pointer.type_.in_memory() {
+            true => {
+                let copy = self.alloca("copy", &pointer.type_);
+
+                self.copy(output, &pointer.type_, &pointer.repr, &copy)?;
+
+                copy
+            }
+            false => {
+                let register = self.register();
+                let type_ = pointer.type_.llvm_type();
+
+                writeln!(
+                    output,
+                    "  {} = load {}, {}* {}",
+                    register, type_, type_, pointer.repr
+                )?;
+
+                register
+            }
+        };
+
+        Ok(Value {
+            repr,
+            type_: pointer.type_.clone(),
+        })
+    }
+
+    /// Writes `value` to the memory `pointer` points to.
+    pub fn store(
+        &mut self,
+        output: &mut dyn io::Write,
+        value: &Value,
+        pointer: &str,
+    ) -> io::Result<()> {
+        if value.type_.in_memory() {
+            return self.copy(output, &value.type_, &value.repr, pointer);
+        }
+
+        let type_ = value.type_.llvm_type();
+
+        writeln!(
+            output,
+            "  store {} {}, {}* {}",
+            type_, value.repr, type_, pointer
+        )
+    }
+
+    /// Copies a value of a type kept in memory with `llvm.memcpy`, the size
+    /// is computed from the address of the element after the first one.
+    fn copy(
+        &mut self,
+        output: &mut dyn io::Write,
+        type_: &Type,
+        source: &str,
+        destination: &str,
+    ) -> io::Result<()> {
+        self.intrinsic("declare void @llvm.memcpy.p0i8.p0i8.i64(i8*, i8*, i64, i1)".to_string());
+
+        let type_ = type_.llvm_type();
+        let source_bytes = self.register();
+        let destination_bytes = self.register();
+
+        writeln!(
+            output,
+            "  {} = bitcast {}* {} to i8*",
+            source_bytes, type_, source
+        )?;
+        writeln!(
+            output,
+            "  {} = bitcast {}* {} to i8*",
+            destination_bytes, type_, destination
+        )?;
+        writeln!(
+            output,
+            "  call void @llvm.memcpy.p0i8.p0i8.i64(i8* {}, i8* {}, \
+             i64 ptrtoint ({}* getelementptr ({}, {}* null, i32 1) to i64), i1 false)",
+            destination_bytes, source_bytes, type_, type_, type_
+        )
+    }
+
+    pub fn take_allocas(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.allocas)
+    }
+
+    /// Records the declaration of an LLVM intrinsic used by the module.
+    pub fn intrinsic(&mut self, declaration: String) {
+        self.intrinsics.insert(declaration);
+    }
+
+    pub fn take_intrinsics(&mut self) -> BTreeSet<String> {
+        std::mem::take(&mut self.intrinsics)
+    }
+
+    /// Records the definition of a global, which is written after the
+    /// functions of the module.
+    pub fn global(&mut self, definition: String) {
+        self.globals.push(definition);
+    }
+
+    pub fn take_globals(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.globals)
+    }
+
+    /// Binds a variable to the pointer of its stack slot.
+    pub fn declare(&mut self, variable: ASTRef<Variable>, pointer: Value) {
+        self.variables.insert(variable, pointer);
+    }
+
+    /// The stack slot of the variable an identifier resolves to.
+    pub fn lookup(&self, ident: ASTRef<Ident>) -> &Value {
+        let variable = self
+            .resolution
+            .variable(ident)
+            .expect("unresolved variable in code generation");
+
+        self.variables
+            .get(&variable)
+            .expect("use of variable before its declaration")
+    }
+
+    pub fn push_loop(&mut self, loop_: Loop) {
+        self.loops.push(loop_);
+    }
+
+    pub fn pop_loop(&mut self) {
+        self.loops.pop().expect("no loop to pop");
+    }
+
+    /// The innermost loop, `break` and `continue` outside of loops are
+    /// rejected by the resolver.
+    pub fn current_loop(&self) -> &Loop {
+        self.loops.last().expect("break or continue outside of loop")
+    }
+
+    /// Starts a new basic block.
+    pub fn label(&mut self, output: &mut dyn io::Write, label: &str) -> io::Result<()> {
+        writeln!(output, "{}:", label)?;
+        self.terminated = false;
+
+        Ok(())
+    }
+
+    /// Branches to `label` unless the current basic block is already terminated.
+    pub fn branch(&mut self, output: &mut dyn io::Write, label: &str) -> io::Result<()> {
+        if !self.terminated {
+            writeln!(output, "  br label %{}", label)?;
+            self.terminated = true;
+        }
+
+        Ok(())
+    }
+}
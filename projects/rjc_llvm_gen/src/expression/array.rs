@@ -0,0 +1,105 @@
+use std::io;
+
+use rjc_ast::{
+    expression::{ArrayLiteral, ArrayRepeat},
+    Type, AST,
+};
+
+use crate::{
+    context::{Context, Value},
+    IRGen, LLVMType,
+};
+
+impl IRGen for ArrayLiteral {
+    type Output = Value;
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let mut values = vec![];
+
+        for element in &self.elements {
+            values.push(pool.get(*element).ir(pool, output, context)?);
+        }
+
+        let type_ = Type::Array(Box::new(values[0].type_.clone()), values.len());
+        let array_type = type_.llvm_type();
+        let array = context.alloca("array", &type_);
+
+        for (i, value) in values.iter().enumerate() {
+            let pointer = context.register();
+
+            writeln!(
+                output,
+                "  {} = getelementptr {}, {}* {}, i64 0, i64 {}",
+                pointer, array_type, array_type, array, i
+            )?;
+            context.store(output, value, &pointer)?;
+        }
+
+        Ok(Value { repr: array, type_ })
+    }
+}
+
+impl IRGen for ArrayRepeat {
+    type Output = Value;
+
+    /// The value is evaluated once and stored into every element by a loop,
+    /// which keeps the code small for long arrays.
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let value = pool.get(self.value).ir(pool, output, context)?;
+        let type_ = Type::Array(Box::new(value.type_.clone()), self.length);
+        let array_type = type_.llvm_type();
+
+        let array = context.alloca("repeat", &type_);
+        let counter = context.alloca("repeat.index", &Type::USize);
+
+        let cond_label = context.unique("repeat.cond");
+        let body_label = context.unique("repeat.body");
+        let end_label = context.unique("repeat.end");
+
+        writeln!(output, "  store i64 0, i64* {}", counter)?;
+        context.branch(output, &cond_label)?;
+
+        context.label(output, &cond_label)?;
+        let index = context.register();
+        let done = context.register();
+        writeln!(output, "  {} = load i64, i64* {}", index, counter)?;
+        writeln!(
+            output,
+            "  {} = icmp uge i64 {}, {}",
+            done, index, self.length
+        )?;
+        writeln!(
+            output,
+            "  br i1 {}, label %{}, label %{}",
+            done, end_label, body_label
+        )?;
+        context.terminated = true;
+
+        context.label(output, &body_label)?;
+        let pointer = context.register();
+        let next = context.register();
+        writeln!(
+            output,
+            "  {} = getelementptr {}, {}* {}, i64 0, i64 {}",
+            pointer, array_type, array_type, array, index
+        )?;
+        context.store(output, &value, &pointer)?;
+        writeln!(output, "  {} = add i64 {}, 1", next, index)?;
+        writeln!(output, "  store i64 {}, i64* {}", next, counter)?;
+        context.branch(output, &cond_label)?;
+
+        context.label(output, &end_label)?;
+
+        Ok(Value { repr: array, type_ })
+    }
+}
@@ -0,0 +1,71 @@
+use std::io;
+
+use rjc_ast::{
+    expression::{Bitwise, BitwiseOp},
+    AST,
+};
+
+use crate::{
+    context::{Context, Value},
+    IRGen, LLVMType,
+};
+
+impl IRGen for Bitwise {
+    type Output = Value;
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let mut value = pool.get(self.first).ir(pool, output, context)?;
+
+        for op in &self.rest {
+            let instruction = match op {
+                BitwiseOp::Or(_) => "or",
+                BitwiseOp::Xor(_) => "xor",
+                BitwiseOp::And(_) => "and",
+                BitwiseOp::Shl(_) => "shl",
+                // Signed integers shift in their sign bit.
+                BitwiseOp::Shr(_) if value.type_.is_signed() => "ashr",
+                BitwiseOp::Shr(_) => "lshr",
+            };
+
+            let type_ = value.type_.llvm_type();
+            let mut right = pool.get(op.expression()).ir(pool, output, context)?;
+
+            // Shift amounts wrap around at the bit width, LLVM does not
+            // define shifts by the bit width or more.
+            if matches!(op, BitwiseOp::Shl(_) | BitwiseOp::Shr(_)) {
+                let amount = context.register();
+
+                writeln!(
+                    output,
+                    "  {} = and {} {}, {}",
+                    amount,
+                    type_,
+                    right.repr,
+                    value.type_.bits() - 1
+                )?;
+
+                right.repr = amount;
+            }
+
+            let register = context.register();
+
+            writeln!(
+                output,
+                "  {} = {} {} {}, {}",
+                register, instruction, type_, value.repr, right.repr
+            )?;
+
+            value = Value {
+                repr: register,
+                type_: value.type_,
+            };
+        }
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,164 @@
+use std::{cmp::Ordering, io};
+
+use rjc_ast::{expression::Cast, Type, AST};
+
+use crate::{
+    context::{Context, Value},
+    IRGen, LLVMType,
+};
+
+impl IRGen for Cast {
+    type Output = Value;
+
+    /// Narrowing casts truncate to the low bits. Widening casts sign extend
+    /// signed integers and zero extend unsigned integers and bools. Casts
+    /// between integers of the same width keep the bits as they are.
+    /// Integers convert to the nearest float. Floats convert to integers by
+    /// rounding towards zero and saturate at the bounds of the integer type,
+    /// NaN becomes `0`. Chars convert like `u32`, integers that are not a
+    /// Unicode scalar value convert to the replacement character U+FFFD.
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let value = pool.get(self.expression).ir(pool, output, context)?;
+
+        let from_float = value.type_.is_float();
+        let to_float = self.type_.is_float();
+        let ordering = value.type_.bits().cmp(&self.type_.bits());
+
+        let instruction = match ordering {
+            _ if self.type_ == Type::Char && value.type_ != Type::Char => {
+                return character(output, context, value);
+            }
+            _ if from_float && !to_float => {
+                return saturate(output, context, value, &self.type_);
+            }
+            _ if to_float && !from_float && value.type_.is_signed() => "sitofp",
+            _ if to_float && !from_float => "uitofp",
+            Ordering::Greater if to_float => "fptrunc",
+            Ordering::Less if to_float => "fpext",
+            Ordering::Greater => "trunc",
+            Ordering::Less if value.type_.is_signed() => "sext",
+            Ordering::Less => "zext",
+            Ordering::Equal => {
+                return Ok(Value {
+                    repr: value.repr,
+                    type_: self.type_.clone(),
+                })
+            }
+        };
+
+        let register = context.register();
+
+        writeln!(
+            output,
+            "  {} = {} {} {} to {}",
+            register,
+            instruction,
+            value.type_.llvm_type(),
+            value.repr,
+            self.type_.llvm_type()
+        )?;
+
+        Ok(Value {
+            repr: register,
+            type_: self.type_.clone(),
+        })
+    }
+}
+
+/// Converts an integer to a char. The integer is widened to 64 bits first,
+/// so that negative values are out of range as well.
+fn character(
+    output: &mut dyn io::Write,
+    context: &mut Context,
+    value: Value,
+) -> io::Result<Value> {
+    let wide = match value.type_.bits() {
+        64 => value.repr,
+        _ => {
+            let extension = if value.type_.is_signed() { "sext" } else { "zext" };
+            let register = context.register();
+
+            writeln!(
+                output,
+                "  {} = {} {} {} to i64",
+                register,
+                extension,
+                value.type_.llvm_type(),
+                value.repr
+            )?;
+
+            register
+        }
+    };
+
+    let in_range = context.register();
+    let offset = context.register();
+    let not_surrogate = context.register();
+    let valid = context.register();
+    let low = context.register();
+    let register = context.register();
+
+    writeln!(output, "  {} = icmp ule i64 {}, 1114111", in_range, wide)?;
+    writeln!(output, "  {} = sub i64 {}, 55296", offset, wide)?;
+    writeln!(output, "  {} = icmp uge i64 {}, 2048", not_surrogate, offset)?;
+    writeln!(
+        output,
+        "  {} = and i1 {}, {}",
+        valid, in_range, not_surrogate
+    )?;
+    writeln!(output, "  {} = trunc i64 {} to i32", low, wide)?;
+    writeln!(
+        output,
+        "  {} = select i1 {}, i32 {}, i32 65533",
+        register, valid, low
+    )?;
+
+    Ok(Value {
+        repr: register,
+        type_: Type::Char,
+    })
+}
+
+/// Converts a float to an integer with the saturating conversion intrinsics.
+fn saturate(
+    output: &mut dyn io::Write,
+    context: &mut Context,
+    value: Value,
+    type_: &Type,
+) -> io::Result<Value> {
+    let intrinsic = format!(
+        "llvm.fpto{}i.sat.{}.{}",
+        if type_.is_signed() { "s" } else { "u" },
+        type_.llvm_type(),
+        if value.type_ == Type::F32 { "f32" } else { "f64" }
+    );
+
+    context.intrinsic(format!(
+        "declare {} @{}({})",
+        type_.llvm_type(),
+        intrinsic,
+        value.type_.llvm_type()
+    ));
+
+    let register = context.register();
+
+    writeln!(
+        output,
+        "  {} = call {} @{}({} {})",
+        register,
+        type_.llvm_type(),
+        intrinsic,
+        value.type_.llvm_type(),
+        value.repr
+    )?;
+
+    Ok(Value {
+        repr: register,
+        type_: type_.clone(),
+    })
+}
@@ -0,0 +1,132 @@
+use std::io;
+
+use rjc_ast::{
+    expression::{Cmp, CmpOp},
+    Type, AST,
+};
+
+use crate::{
+    context::{Context, Value},
+    IRGen, LLVMType,
+};
+
+impl IRGen for Cmp {
+    type Output = Value;
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let left = pool.get(self.left).ir(pool, output, context)?;
+        let right = pool.get(self.right).ir(pool, output, context)?;
+
+        if left.type_ == Type::Str {
+            return string_equality(output, context, &self.op, left, right);
+        }
+
+        let (instruction, predicate) = if left.type_.is_float() {
+            ("fcmp", float_predicate(&self.op))
+        } else {
+            ("icmp", int_predicate(&self.op, left.type_.is_signed()))
+        };
+
+        let register = context.register();
+
+        writeln!(
+            output,
+            "  {} = {} {} {} {}, {}",
+            register,
+            instruction,
+            predicate,
+            left.type_.llvm_type(),
+            left.repr,
+            right.repr
+        )?;
+
+        Ok(Value {
+            repr: register,
+            type_: Type::Bool,
+        })
+    }
+}
+
+/// Strings are equal if their bytes are, which `rj_str_eq` compares.
+fn string_equality(
+    output: &mut dyn io::Write,
+    context: &mut Context,
+    op: &CmpOp,
+    left: Value,
+    right: Value,
+) -> io::Result<Value> {
+    let mut arguments = Vec::new();
+
+    for value in [&left, &right] {
+        let pointer = context.register();
+        let length = context.register();
+        let type_ = value.type_.llvm_type();
+
+        writeln!(
+            output,
+            "  {} = extractvalue {} {}, 0",
+            pointer, type_, value.repr
+        )?;
+        writeln!(
+            output,
+            "  {} = extractvalue {} {}, 1",
+            length, type_, value.repr
+        )?;
+
+        arguments.push(format!("i8* {}, i64 {}", pointer, length));
+    }
+
+    let mut register = context.register();
+
+    writeln!(
+        output,
+        "  {} = call zeroext i1 @rj_str_eq({})",
+        register,
+        arguments.join(", ")
+    )?;
+
+    if matches!(op, CmpOp::Ne) {
+        let equal = register;
+
+        register = context.register();
+        writeln!(output, "  {} = xor i1 {}, true", register, equal)?;
+    }
+
+    Ok(Value {
+        repr: register,
+        type_: Type::Bool,
+    })
+}
+
+/// Float comparisons are ordered, they are false if either operand is NaN.
+/// `!=` is unordered instead, so it is true if either operand is NaN.
+fn float_predicate(op: &CmpOp) -> &'static str {
+    match op {
+        CmpOp::Eq => "oeq",
+        CmpOp::Ne => "une",
+        CmpOp::Le => "ole",
+        CmpOp::Ge => "oge",
+        CmpOp::Lt => "olt",
+        CmpOp::Gt => "ogt",
+    }
+}
+
+fn int_predicate(op: &CmpOp, signed: bool) -> &'static str {
+    match op {
+        CmpOp::Eq => "eq",
+        CmpOp::Ne => "ne",
+        CmpOp::Le if signed => "sle",
+        CmpOp::Ge if signed => "sge",
+        CmpOp::Lt if signed => "slt",
+        CmpOp::Gt if signed => "sgt",
+        CmpOp::Le => "ule",
+        CmpOp::Ge => "uge",
+        CmpOp::Lt => "ult",
+        CmpOp::Gt => "ugt",
+    }
+}
@@ -0,0 +1,46 @@
+use std::io;
+
+use rjc_ast::{expression::Expression, AST};
+
+use crate::{
+    context::{Context, Value},
+    IRGen,
+};
+
+impl IRGen for Expression {
+    type Output = Value;
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        match self {
+            Expression::Literal((node, _)) => pool.get(*node).ir(pool, output, context),
+            Expression::Ident((node, _)) => {
+                let pointer = context.lookup(*node).clone();
+
+                context.load(output, &pointer)
+            }
+            Expression::Call((node, _)) => Ok(pool
+                .get(*node)
+                .ir(pool, output, context)?
+                .into_iter()
+                .next()
+                .expect("function call in expression returns no value")),
+            Expression::Cmp((node, _)) => pool.get(*node).ir(pool, output, context),
+            Expression::Sum((node, _)) => pool.get(*node).ir(pool, output, context),
+            Expression::Product((node, _)) => pool.get(*node).ir(pool, output, context),
+            Expression::Logical((node, _)) => pool.get(*node).ir(pool, output, context),
+            Expression::Unary((node, _)) => pool.get(*node).ir(pool, output, context),
+            Expression::Bitwise((node, _)) => pool.get(*node).ir(pool, output, context),
+            Expression::Cast((node, _)) => pool.get(*node).ir(pool, output, context),
+            Expression::Field((node, _)) => pool.get(*node).ir(pool, output, context),
+            Expression::StructLiteral((node, _)) => pool.get(*node).ir(pool, output, context),
+            Expression::Index((node, _)) => pool.get(*node).ir(pool, output, context),
+            Expression::ArrayLiteral((node, _)) => pool.get(*node).ir(pool, output, context),
+            Expression::ArrayRepeat((node, _)) => pool.get(*node).ir(pool, output, context),
+        }
+    }
+}
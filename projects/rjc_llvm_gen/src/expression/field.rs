@@ -0,0 +1,53 @@
+use std::io;
+
+use rjc_ast::{expression::Field, ASTRef, ASTType, Ident, Type, Variable, AST};
+
+use crate::{
+    context::{Context, Value},
+    IRGen,
+};
+
+use super::place::field;
+
+impl IRGen for Field {
+    type Output = Value;
+
+    /// Fields are loaded through a pointer into the struct, like elements
+    /// of arrays.
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let pointer = field(pool, output, context, self)?;
+
+        context.load(output, &pointer)
+    }
+}
+
+/// The position and type of the field `field` of the struct type `struct_`.
+pub fn field_index(
+    pool: &AST,
+    context: &Context,
+    struct_: &Type,
+    field: ASTRef<Ident>,
+) -> (usize, Type) {
+    let name = match struct_ {
+        Type::Struct(name) => name,
+
+        _ => panic!("field access on non-struct type in code generation"),
+    };
+
+    let struct_ = context
+        .resolution
+        .struct_(name)
+        .expect("unresolved struct in code generation");
+
+    let (index, variable) = pool
+        .get(struct_)
+        .field(pool, &pool.get(field).name)
+        .expect("unknown field in code generation");
+
+    (index, context.types.variable(Variable::pool_ref(variable.id)).clone())
+}
@@ -0,0 +1,27 @@
+use std::io;
+
+use rjc_ast::{expression::Index, AST};
+
+use crate::{
+    context::{Context, Value},
+    IRGen,
+};
+
+use super::place::element;
+
+impl IRGen for Index {
+    type Output = Value;
+
+    /// Elements are loaded through a pointer into the array, so only the
+    /// element is read and not the whole array.
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let pointer = element(pool, output, context, self)?;
+
+        context.load(output, &pointer)
+    }
+}
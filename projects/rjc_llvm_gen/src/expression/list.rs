@@ -0,0 +1,37 @@
+use std::io;
+
+use rjc_ast::{
+    expression::{Expression, ExpressionList},
+    AST,
+};
+
+use crate::{
+    context::{Context, Value},
+    IRGen,
+};
+
+impl IRGen for ExpressionList {
+    type Output = Vec<Value>;
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let mut values = vec![];
+
+        for expression in &self.list {
+            match pool.get(*expression) {
+                // A call in a list contributes all of its return values
+                Expression::Call((node, _)) => {
+                    values.extend(pool.get(*node).ir(pool, output, context)?)
+                }
+
+                expression => values.push(expression.ir(pool, output, context)?),
+            }
+        }
+
+        Ok(values)
+    }
+}
@@ -0,0 +1,67 @@
+use std::{fmt::Write as _, io};
+
+use rjc_ast::{
+    expression::{Literal, LiteralValue},
+    ASTType, Type, AST,
+};
+
+use crate::{
+    context::{Context, Value},
+    IRGen,
+};
+
+impl IRGen for Literal {
+    type Output = Value;
+
+    fn ir(
+        &self,
+        _: &AST,
+        _: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let type_ = context.types.literal(Self::pool_ref(self.id)).clone();
+
+        // An `f32` constant is the double of the rounded `f32` value.
+        let repr = match &self.value {
+            LiteralValue::Integer { value, .. } => value.to_string(),
+            LiteralValue::Float { value, .. } if type_ == Type::F32 => float(*value as f32 as f64),
+            LiteralValue::Float { value, .. } => float(*value),
+            LiteralValue::Bool(value) => value.to_string(),
+            LiteralValue::Char(value) => (*value as u32).to_string(),
+            LiteralValue::Str(value) => string(context, value),
+        };
+
+        Ok(Value { repr, type_ })
+    }
+}
+
+/// LLVM only accepts decimal float constants that are exact, so floats are
+/// written as the hexadecimal bits of a double.
+pub fn float(value: f64) -> String {
+    format!("0x{:016X}", value.to_bits())
+}
+
+/// Places the bytes of a string in a read-only global and returns the
+/// constant pointing to them.
+pub fn string(context: &mut Context, value: &str) -> String {
+    let name = context.unique("str");
+    let length = value.len();
+    let mut bytes = String::new();
+
+    for byte in value.bytes() {
+        match byte {
+            b' '..=b'~' if byte != b'"' && byte != b'\\' => bytes.push(byte as char),
+            _ => write!(bytes, "\\{:02X}", byte).expect("write to string"),
+        }
+    }
+
+    context.global(format!(
+        "@{} = private unnamed_addr constant [{} x i8] c\"{}\"",
+        name, length, bytes
+    ));
+
+    format!(
+        "{{ i8* getelementptr inbounds ([{} x i8], [{} x i8]* @{}, i64 0, i64 0), i64 {} }}",
+        length, length, name, length
+    )
+}
@@ -0,0 +1,62 @@
+use std::io;
+
+use rjc_ast::{
+    expression::{Logical, LogicalOp},
+    Type, AST,
+};
+
+use crate::{
+    context::{Context, Value},
+    IRGen,
+};
+
+impl IRGen for Logical {
+    type Output = Value;
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        // The result is kept in a stack slot, every operand that gets
+        // evaluated overwrites it and the first one deciding the result
+        // branches to the end.
+        let result = context.alloca("logical", &Type::Bool);
+        let end_label = format!("logical.{}.end", self.id);
+
+        let mut value = pool.get(self.first).ir(pool, output, context)?;
+        writeln!(output, "  store i1 {}, i1* {}", value.repr, result)?;
+
+        for op in &self.rest {
+            let next_label = context.unique("logical.next");
+
+            let (expression, true_label, false_label) = match op {
+                LogicalOp::And(expression) => (expression, &next_label, &end_label),
+                LogicalOp::Or(expression) => (expression, &end_label, &next_label),
+            };
+
+            writeln!(
+                output,
+                "  br i1 {}, label %{}, label %{}",
+                value.repr, true_label, false_label
+            )?;
+            context.terminated = true;
+
+            context.label(output, &next_label)?;
+            value = pool.get(*expression).ir(pool, output, context)?;
+            writeln!(output, "  store i1 {}, i1* {}", value.repr, result)?;
+        }
+
+        context.branch(output, &end_label)?;
+        context.label(output, &end_label)?;
+
+        let register = context.register();
+        writeln!(output, "  {} = load i1, i1* {}", register, result)?;
+
+        Ok(Value {
+            repr: register,
+            type_: Type::Bool,
+        })
+    }
+}
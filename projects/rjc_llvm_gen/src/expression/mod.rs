@@ -0,0 +1,16 @@
+mod array;
+mod bitwise;
+mod cast;
+mod cmp;
+#[allow(clippy::module_inception)]
+mod expression;
+pub mod field;
+mod index;
+mod list;
+pub mod literal;
+mod logical;
+pub mod place;
+mod product;
+mod struct_literal;
+mod sum;
+mod unary;
@@ -0,0 +1,160 @@
+use std::io;
+
+use rjc_ast::{
+    expression::{Expression, Field, Index},
+    ASTRef, Type, AST,
+};
+
+use crate::{
+    context::{Context, Value},
+    IRGen, LLVMType,
+};
+
+use super::field::field_index;
+
+/// The pointer to the storage an expression refers to, the stack slot of a
+/// variable or a field or element within it. Other values are stored in a
+/// temporary slot first.
+pub fn place(
+    pool: &AST,
+    output: &mut dyn io::Write,
+    context: &mut Context,
+    expression: ASTRef<Expression>,
+) -> io::Result<Value> {
+    match pool.get(expression) {
+        Expression::Ident((node, _)) => Ok(context.lookup(*node).clone()),
+        Expression::Field((node, _)) => field(pool, output, context, pool.get(*node)),
+        Expression::Index((node, _)) => element(pool, output, context, pool.get(*node)),
+
+        expression => {
+            let value = expression.ir(pool, output, context)?;
+
+            // Values kept in memory already are a pointer to their own copy.
+            if value.type_.in_memory() {
+                return Ok(value);
+            }
+
+            let pointer = context.alloca("temporary", &value.type_);
+
+            context.store(output, &value, &pointer)?;
+
+            Ok(Value {
+                repr: pointer,
+                type_: value.type_,
+            })
+        }
+    }
+}
+
+/// The pointer to the struct field `field` refers to.
+pub fn field(
+    pool: &AST,
+    output: &mut dyn io::Write,
+    context: &mut Context,
+    field: &Field,
+) -> io::Result<Value> {
+    let struct_ = place(pool, output, context, field.expression)?;
+    let (index, type_) = field_index(pool, context, &struct_.type_, field.field);
+    let register = context.register();
+    let struct_type = struct_.type_.llvm_type();
+
+    writeln!(
+        output,
+        "  {} = getelementptr {}, {}* {}, i32 0, i32 {}",
+        register, struct_type, struct_type, struct_.repr, index
+    )?;
+
+    Ok(Value {
+        repr: register,
+        type_,
+    })
+}
+
+/// The pointer to the array element `index` refers to.
+pub fn element(
+    pool: &AST,
+    output: &mut dyn io::Write,
+    context: &mut Context,
+    index: &Index,
+) -> io::Result<Value> {
+    let array = place(pool, output, context, index.expression)?;
+    let (element, length) = match &array.type_ {
+        Type::Array(element, length) => (element.as_ref().clone(), *length),
+
+        _ => panic!("index into non-array type in code generation"),
+    };
+
+    let value = pool.get(index.index).ir(pool, output, context)?;
+    let offset = bounds_check(output, context, value, length)?;
+    let register = context.register();
+    let array_type = array.type_.llvm_type();
+
+    writeln!(
+        output,
+        "  {} = getelementptr {}, {}* {}, i64 0, i64 {}",
+        register, array_type, array_type, array.repr, offset
+    )?;
+
+    Ok(Value {
+        repr: register,
+        type_: element,
+    })
+}
+
+/// Widens an index to `i64` and traps through `rj_index_out_of_bounds` unless
+/// it is below `length`. Negative indices wrap to large offsets and trap as
+/// well. Returns the widened index.
+fn bounds_check(
+    output: &mut dyn io::Write,
+    context: &mut Context,
+    index: Value,
+    length: usize,
+) -> io::Result<String> {
+    let offset = match index.type_.bits() {
+        64 => index.repr,
+        _ => {
+            let extension = if index.type_.is_signed() { "sext" } else { "zext" };
+            let register = context.register();
+
+            writeln!(
+                output,
+                "  {} = {} {} {} to i64",
+                register,
+                extension,
+                index.type_.llvm_type(),
+                index.repr
+            )?;
+
+            register
+        }
+    };
+
+    let out_of_bounds_label = context.unique("index.out_of_bounds");
+    let ok_label = context.unique("index.ok");
+
+    let out_of_bounds = context.register();
+    writeln!(
+        output,
+        "  {} = icmp uge i64 {}, {}",
+        out_of_bounds, offset, length
+    )?;
+    writeln!(
+        output,
+        "  br i1 {}, label %{}, label %{}",
+        out_of_bounds, out_of_bounds_label, ok_label
+    )?;
+    context.terminated = true;
+
+    context.label(output, &out_of_bounds_label)?;
+    writeln!(
+        output,
+        "  call void @rj_index_out_of_bounds(i64 {}, i64 {})",
+        offset, length
+    )?;
+    writeln!(output, "  unreachable")?;
+    context.terminated = true;
+
+    context.label(output, &ok_label)?;
+
+    Ok(offset)
+}
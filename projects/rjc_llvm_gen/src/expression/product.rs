@@ -0,0 +1,174 @@
+use std::io;
+
+use rjc_ast::{
+    expression::{Product, ProductOp},
+    AST,
+};
+
+use crate::{
+    context::{Context, Value},
+    IRGen, LLVMType,
+};
+
+impl IRGen for Product {
+    type Output = Value;
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let mut value = pool.get(self.first).ir(pool, output, context)?;
+
+        for op in &self.rest {
+            let register = match op {
+                ProductOp::Mul(expression) => {
+                    let right = pool.get(*expression).ir(pool, output, context)?;
+                    let register = context.register();
+
+                    writeln!(
+                        output,
+                        "  {} = {} {} {}, {}",
+                        register,
+                        if value.type_.is_float() { "fmul" } else { "mul" },
+                        value.type_.llvm_type(),
+                        value.repr,
+                        right.repr
+                    )?;
+
+                    register
+                }
+                ProductOp::Div(expression) => {
+                    let right = pool.get(*expression).ir(pool, output, context)?;
+
+                    divide(output, context, false, &value, &right)?
+                }
+                ProductOp::Rem(expression) => {
+                    let right = pool.get(*expression).ir(pool, output, context)?;
+
+                    divide(output, context, true, &value, &right)?
+                }
+            };
+
+            value = Value {
+                repr: register,
+                type_: value.type_,
+            };
+        }
+
+        Ok(value)
+    }
+}
+
+/// Emits a division or remainder. Signed division truncates towards zero and
+/// the remainder has the sign of the dividend. Dividing by zero calls into the
+/// runtime, which aborts the program. `MIN / -1` of a signed type wraps around
+/// to `MIN` and `MIN % -1` is `0`, instead of being undefined as in LLVM.
+/// Float division follows IEEE 754 and never aborts.
+fn divide(
+    output: &mut dyn io::Write,
+    context: &mut Context,
+    remainder: bool,
+    left: &Value,
+    right: &Value,
+) -> io::Result<String> {
+    let type_ = left.type_.llvm_type();
+
+    if left.type_.is_float() {
+        let result = context.register();
+
+        writeln!(
+            output,
+            "  {} = {} {} {}, {}",
+            result,
+            if remainder { "frem" } else { "fdiv" },
+            type_,
+            left.repr,
+            right.repr
+        )?;
+
+        return Ok(result);
+    }
+    let signed = left.type_.is_signed();
+
+    let instruction = match (signed, remainder) {
+        (true, false) => "sdiv",
+        (true, true) => "srem",
+        (false, false) => "udiv",
+        (false, true) => "urem",
+    };
+
+    let zero_label = context.unique("div.zero");
+    let ok_label = context.unique("div.ok");
+
+    let is_zero = context.register();
+    writeln!(
+        output,
+        "  {} = icmp eq {} {}, 0",
+        is_zero, type_, right.repr
+    )?;
+    writeln!(
+        output,
+        "  br i1 {}, label %{}, label %{}",
+        is_zero, zero_label, ok_label
+    )?;
+    context.terminated = true;
+
+    context.label(output, &zero_label)?;
+    writeln!(output, "  call void @rj_divide_by_zero()")?;
+    writeln!(output, "  unreachable")?;
+    context.terminated = true;
+
+    context.label(output, &ok_label)?;
+
+    if !signed {
+        let result = context.register();
+
+        writeln!(
+            output,
+            "  {} = {} {} {}, {}",
+            result, instruction, type_, left.repr, right.repr
+        )?;
+
+        return Ok(result);
+    }
+
+    // Divide by 1 instead of -1, which gives the wrapped quotient after
+    // negation and the correct remainder of 0.
+    let is_minus_one = context.register();
+    let divisor = context.register();
+    let result = context.register();
+
+    writeln!(
+        output,
+        "  {} = icmp eq {} {}, -1",
+        is_minus_one, type_, right.repr
+    )?;
+    writeln!(
+        output,
+        "  {} = select i1 {}, {} 1, {} {}",
+        divisor, is_minus_one, type_, type_, right.repr
+    )?;
+    writeln!(
+        output,
+        "  {} = {} {} {}, {}",
+        result, instruction, type_, left.repr, divisor
+    )?;
+
+    if remainder {
+        return Ok(result);
+    }
+
+    let negated = context.register();
+    let quotient = context.register();
+
+    writeln!(output, "  {} = sub {} 0, {}", negated, type_, left.repr)?;
+    writeln!(
+        output,
+        "  {} = select i1 {}, {} {}, {} {}",
+        quotient, is_minus_one, type_, negated, type_, result
+    )?;
+
+    Ok(quotient)
+}
@@ -0,0 +1,46 @@
+use std::io;
+
+use rjc_ast::{expression::StructLiteral, Type, AST};
+
+use crate::{
+    context::{Context, Value},
+    IRGen, LLVMType,
+};
+
+use super::field::field_index;
+
+impl IRGen for StructLiteral {
+    type Output = Value;
+
+    /// Field values are evaluated in source order and stored into the
+    /// struct at their declared position.
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let type_ = Type::Struct(pool.get(self.ident).name.clone());
+        let struct_type = type_.llvm_type();
+        let struct_ = context.alloca("struct", &type_);
+
+        for field in &self.fields {
+            let field = pool.get(*field);
+            let value = pool.get(field.expression).ir(pool, output, context)?;
+            let (index, _) = field_index(pool, context, &type_, field.ident);
+            let pointer = context.register();
+
+            writeln!(
+                output,
+                "  {} = getelementptr {}, {}* {}, i32 0, i32 {}",
+                pointer, struct_type, struct_type, struct_, index
+            )?;
+            context.store(output, &value, &pointer)?;
+        }
+
+        Ok(Value {
+            repr: struct_,
+            type_,
+        })
+    }
+}
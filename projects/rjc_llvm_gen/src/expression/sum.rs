@@ -0,0 +1,55 @@
+use std::io;
+
+use rjc_ast::{
+    expression::{Sum, SumOp},
+    AST,
+};
+
+use crate::{
+    context::{Context, Value},
+    IRGen, LLVMType,
+};
+
+impl IRGen for Sum {
+    type Output = Value;
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let mut value = pool.get(self.first).ir(pool, output, context)?;
+
+        for op in &self.rest {
+            let float = value.type_.is_float();
+
+            let (instruction, expression) = match op {
+                SumOp::Add(expression) if float => ("fadd", expression),
+                SumOp::Sub(expression) if float => ("fsub", expression),
+                SumOp::Add(expression) => ("add", expression),
+                SumOp::Sub(expression) => ("sub", expression),
+            };
+
+            let right = pool.get(*expression).ir(pool, output, context)?;
+            let register = context.register();
+
+            writeln!(
+                output,
+                "  {} = {} {} {}, {}",
+                register,
+                instruction,
+                value.type_.llvm_type(),
+                value.repr,
+                right.repr
+            )?;
+
+            value = Value {
+                repr: register,
+                type_: value.type_,
+            };
+        }
+
+        Ok(value)
+    }
+}
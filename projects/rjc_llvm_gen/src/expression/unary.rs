@@ -0,0 +1,46 @@
+use std::io;
+
+use rjc_ast::{
+    expression::{Unary, UnaryOp},
+    AST,
+};
+
+use crate::{
+    context::{Context, Value},
+    IRGen, LLVMType,
+};
+
+impl IRGen for Unary {
+    type Output = Value;
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let value = pool.get(self.expression).ir(pool, output, context)?;
+        let register = context.register();
+        let type_ = value.type_.llvm_type();
+
+        match self.op {
+            UnaryOp::Not => writeln!(
+                output,
+                "  {} = xor {} {}, true",
+                register, type_, value.repr
+            )?,
+            UnaryOp::Neg if value.type_.is_float() => {
+                writeln!(output, "  {} = fneg {} {}", register, type_, value.repr)?
+            }
+            UnaryOp::Neg => writeln!(output, "  {} = sub {} 0, {}", register, type_, value.repr)?,
+            UnaryOp::BitNot => {
+                writeln!(output, "  {} = xor {} {}, -1", register, type_, value.repr)?
+            }
+        }
+
+        Ok(Value {
+            repr: register,
+            type_: value.type_,
+        })
+    }
+}
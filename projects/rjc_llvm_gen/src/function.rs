@@ -0,0 +1,77 @@
+use std::io::{self, Write};
+
+use rjc_ast::{Function, AST};
+
+use crate::{
+    context::{Context, Value},
+    IRGen, LLVMType,
+};
+
+impl IRGen for Function {
+    type Output = ();
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let name = &pool.get(self.ident).name;
+        let mut params = vec![];
+        let mut body = vec![];
+
+        // Return values are written through out-pointers preceding the
+        // arguments, which is the convention `stdlib/start.ll` uses for main.
+        for (i, type_) in self.return_type.list.iter().enumerate() {
+            params.push(format!("{}* %return.{}", type_.llvm_type(), i));
+        }
+
+        context.terminated = false;
+
+        for arg in &self.args {
+            let variable = pool.get(*arg);
+            let arg_name = &pool.get(variable.name).name;
+            let value = Value {
+                repr: format!("%arg.{}", arg_name),
+                type_: context.types.variable(*arg).clone(),
+            };
+
+            params.push(format!("{} {}", value.type_.llvm_value_type(), value.repr));
+
+            // Arguments kept in memory are copied as well, the callee owns
+            // its arguments.
+            let pointer = variable.ir(pool, &mut body, context)?;
+            context.store(&mut body, &value, &pointer.repr)?;
+        }
+
+        pool.get(self.block).ir(pool, &mut body, context)?;
+
+        if !context.terminated {
+            writeln!(body, "  ret void")?;
+        }
+
+        writeln!(output, "define void {}({}) {{", mangle(name), params.join(", "))?;
+        writeln!(output, "entry:")?;
+
+        for alloca in context.take_allocas() {
+            writeln!(output, "{}", alloca)?;
+        }
+
+        output.write_all(&body)?;
+        writeln!(output, "}}")?;
+        writeln!(output)?;
+
+        Ok(())
+    }
+}
+
+/// The LLVM name of a function. Functions other than `main`, which
+/// `stdlib/start.ll` calls, get a prefix so they cannot clash with libc or
+/// the runtime, e.g. a function called `exit`.
+pub fn mangle(name: &str) -> String {
+    match name {
+        "main" => "@main".to_string(),
+
+        _ => format!("@rj.fn.{}", name),
+    }
+}
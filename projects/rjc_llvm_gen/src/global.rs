@@ -0,0 +1,90 @@
+use std::io;
+
+use rjc_ast::{ASTType, Global, Type, AST};
+use rjc_typer::Constant;
+
+use crate::{
+    context::{Context, Value},
+    expression::literal,
+    IRGen, LLVMType,
+};
+
+impl IRGen for Global {
+    type Output = ();
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let variable = pool.get(self.variable);
+        let name = format!("@global.{}", pool.get(variable.name).name);
+        let type_ = context.types.variable(self.variable);
+        let value = context.types.global(Self::pool_ref(self.id));
+        let value = constant(pool, context, value, type_);
+
+        writeln!(
+            output,
+            "{} = internal {} {} {}",
+            name,
+            if self.constant { "constant" } else { "global" },
+            type_.llvm_type(),
+            value
+        )?;
+
+        context.declare(
+            self.variable,
+            Value {
+                repr: name,
+                type_: type_.clone(),
+            },
+        );
+
+        Ok(())
+    }
+}
+
+/// The LLVM constant for a value computed by the typer.
+fn constant(pool: &AST, context: &mut Context, value: &Constant, type_: &Type) -> String {
+    match (value, type_) {
+        (Constant::Integer(value), _) => value.to_string(),
+        (Constant::Float(value), _) => literal::float(*value),
+        (Constant::Bool(value), _) => value.to_string(),
+        (Constant::Char(value), _) => (*value as u32).to_string(),
+        (Constant::Str(value), _) => literal::string(context, value),
+        (Constant::Array(values), _) if values.is_empty() => "zeroinitializer".to_string(),
+        (Constant::Array(values), Type::Array(element, _)) => {
+            let elements: Vec<_> = values
+                .iter()
+                .map(|value| typed(pool, context, value, element))
+                .collect();
+
+            format!("[{}]", elements.join(", "))
+        }
+        (Constant::Struct(values), Type::Struct(name)) => {
+            let struct_ = context
+                .resolution
+                .struct_(name)
+                .expect("unresolved struct in code generation");
+
+            let fields: Vec<_> = values
+                .iter()
+                .zip(&pool.get(struct_).fields)
+                .map(|(value, field)| typed(pool, context, value, context.types.variable(*field)))
+                .collect();
+
+            format!("{{ {} }}", fields.join(", "))
+        }
+
+        _ => panic!("constant does not match its type in code generation"),
+    }
+}
+
+fn typed(pool: &AST, context: &mut Context, value: &Constant, type_: &Type) -> String {
+    format!(
+        "{} {}",
+        type_.llvm_type(),
+        constant(pool, context, value, type_)
+    )
+}
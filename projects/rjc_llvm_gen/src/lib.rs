@@ -0,0 +1,41 @@
+use std::io;
+
+use rjc_ast::AST;
+use rjc_resolver::Resolution;
+use rjc_typer::Types;
+
+mod block;
+mod call;
+mod context;
+mod expression;
+mod function;
+mod global;
+mod module;
+mod statement;
+mod types;
+mod variable;
+
+use context::Context;
+use types::LLVMType;
+
+trait IRGen {
+    type Output;
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output>;
+}
+
+pub fn generate(
+    pool: &AST,
+    resolution: &Resolution,
+    types: &Types,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let mut context = Context::new(resolution, types);
+
+    pool.get(pool.module()).ir(pool, output, &mut context)
+}
@@ -0,0 +1,60 @@
+use std::io;
+
+use rjc_ast::{Module, AST};
+
+use crate::{context::Context, IRGen, LLVMType};
+
+impl IRGen for Module {
+    type Output = ();
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        // Provided by `stdlib/start.ll`.
+        writeln!(output, "declare void @rj_divide_by_zero()")?;
+        writeln!(output, "declare void @rj_index_out_of_bounds(i64, i64)")?;
+        writeln!(output, "declare zeroext i1 @rj_str_eq(i8*, i64, i8*, i64)")?;
+        writeln!(output)?;
+
+        for struct_ in &self.structs {
+            let struct_ = pool.get(*struct_);
+            let name = &pool.get(struct_.ident).name;
+            let fields: Vec<_> = struct_
+                .fields
+                .iter()
+                .map(|field| context.types.variable(*field).llvm_type())
+                .collect();
+
+            writeln!(output, "%struct.{} = type {{ {} }}", name, fields.join(", "))?;
+        }
+
+        if !self.structs.is_empty() {
+            writeln!(output)?;
+        }
+
+        for global in &self.globals {
+            pool.get(*global).ir(pool, output, context)?;
+        }
+
+        if !self.globals.is_empty() {
+            writeln!(output)?;
+        }
+
+        for function in &self.functions {
+            pool.get(*function).ir(pool, output, context)?;
+        }
+
+        for global in context.take_globals() {
+            writeln!(output, "{}", global)?;
+        }
+
+        for intrinsic in context.take_intrinsics() {
+            writeln!(output, "{}", intrinsic)?;
+        }
+
+        Ok(())
+    }
+}
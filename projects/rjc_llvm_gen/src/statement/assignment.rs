@@ -0,0 +1,26 @@
+use std::io;
+
+use rjc_ast::{statement::Assignment, AST};
+
+use crate::{context::Context, expression::place::place, IRGen};
+
+impl IRGen for Assignment {
+    type Output = ();
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let values = pool.get(self.expressions).ir(pool, output, context)?;
+
+        for (target, value) in self.targets.iter().zip(values) {
+            let pointer = place(pool, output, context, *target)?;
+
+            context.store(output, &value, &pointer.repr)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use std::io;
+
+use rjc_ast::{statement::Break, AST};
+
+use crate::{context::Context, IRGen};
+
+impl IRGen for Break {
+    type Output = ();
+
+    fn ir(
+        &self,
+        _pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let label = context.current_loop().break_label.clone();
+
+        context.branch(output, &label)
+    }
+}
@@ -0,0 +1,93 @@
+use std::io;
+
+use rjc_ast::{statement::For, Type, AST};
+
+use crate::{
+    context::{Context, Loop},
+    IRGen,
+};
+
+impl IRGen for For {
+    type Output = ();
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let cond_label = format!("for.{}.cond", self.id);
+        let body_label = format!("for.{}.body", self.id);
+        let step_label = format!("for.{}.step", self.id);
+        let next_label = format!("for.{}.next", self.id);
+        let end_label = format!("for.{}.end", self.id);
+
+        // Both bounds are evaluated once, before the first iteration.
+        let start = pool.get(self.start).ir(pool, output, context)?;
+        let end = pool.get(self.end).ir(pool, output, context)?;
+
+        // The loop counts in a hidden slot, so assignments to the loop
+        // variable do not change the number of iterations.
+        let counter = context.alloca("for", &Type::I32);
+        let variable = pool.get(self.variable).ir(pool, output, context)?;
+
+        writeln!(output, "  store i32 {}, i32* {}", start.repr, counter)?;
+        context.branch(output, &cond_label)?;
+
+        context.label(output, &cond_label)?;
+        let current = context.register();
+        let condition = context.register();
+        let predicate = if self.inclusive { "sle" } else { "slt" };
+
+        writeln!(output, "  {} = load i32, i32* {}", current, counter)?;
+        writeln!(
+            output,
+            "  {} = icmp {} i32 {}, {}",
+            condition, predicate, current, end.repr
+        )?;
+        writeln!(
+            output,
+            "  br i1 {}, label %{}, label %{}",
+            condition, body_label, end_label
+        )?;
+        context.terminated = true;
+
+        context.label(output, &body_label)?;
+        writeln!(output, "  store i32 {}, i32* {}", current, variable.repr)?;
+
+        context.push_loop(Loop {
+            continue_label: step_label.clone(),
+            break_label: end_label.clone(),
+        });
+        pool.get(self.block).ir(pool, output, context)?;
+        context.pop_loop();
+
+        context.branch(output, &step_label)?;
+
+        // An inclusive range ending at the largest i32 would overflow the
+        // counter, so the last iteration leaves the loop before stepping.
+        context.label(output, &step_label)?;
+        let current = context.register();
+        let last = context.register();
+
+        writeln!(output, "  {} = load i32, i32* {}", current, counter)?;
+        writeln!(output, "  {} = icmp eq i32 {}, {}", last, current, end.repr)?;
+        writeln!(
+            output,
+            "  br i1 {}, label %{}, label %{}",
+            last, end_label, next_label
+        )?;
+        context.terminated = true;
+
+        context.label(output, &next_label)?;
+        let next = context.register();
+
+        writeln!(output, "  {} = add i32 {}, 1", next, current)?;
+        writeln!(output, "  store i32 {}, i32* {}", next, counter)?;
+        context.branch(output, &cond_label)?;
+
+        context.label(output, &end_label)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use std::io;
+
+use rjc_ast::{statement::If, AST};
+
+use crate::{context::Context, IRGen};
+
+impl IRGen for If {
+    type Output = ();
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let end_label = format!("if.{}.end", self.id);
+
+        // Every arm is a condition and a block, the `if` itself first
+        // followed by the `else if`s in order.
+        let mut arms = vec![(self.id, "if", self.condition, self.if_block)];
+
+        for else_if in &self.else_ifs {
+            let else_if = pool.get(*else_if);
+            arms.push((else_if.id, "elif", else_if.condition, else_if.block));
+        }
+
+        let else_label = match self.else_block {
+            Some(_) => format!("if.{}.else", self.id),
+            None => end_label.clone(),
+        };
+
+        for (i, (id, prefix, condition, block)) in arms.iter().enumerate() {
+            if i > 0 {
+                context.label(output, &format!("{}.{}.cond", prefix, id))?;
+            }
+
+            let condition = pool.get(*condition).ir(pool, output, context)?;
+
+            let then_label = format!("{}.{}.then", prefix, id);
+            let false_label = match arms.get(i + 1) {
+                Some((id, prefix, _, _)) => format!("{}.{}.cond", prefix, id),
+                None => else_label.clone(),
+            };
+
+            writeln!(
+                output,
+                "  br i1 {}, label %{}, label %{}",
+                condition.repr, then_label, false_label
+            )?;
+            context.terminated = true;
+
+            context.label(output, &then_label)?;
+            pool.get(*block).ir(pool, output, context)?;
+            context.branch(output, &end_label)?;
+        }
+
+        if let Some(else_block) = self.else_block {
+            context.label(output, &else_label)?;
+            pool.get(else_block).ir(pool, output, context)?;
+            context.branch(output, &end_label)?;
+        }
+
+        context.label(output, &end_label)?;
+
+        Ok(())
+    }
+}
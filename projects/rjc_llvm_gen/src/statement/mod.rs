@@ -0,0 +1,9 @@
+mod assignment;
+mod break_;
+mod continue_;
+mod for_;
+mod if_;
+mod return_;
+mod while_;
+#[allow(clippy::module_inception)]
+mod statement;
@@ -0,0 +1,27 @@
+use std::io;
+
+use rjc_ast::{statement::Return, AST};
+
+use crate::{context::Context, IRGen};
+
+impl IRGen for Return {
+    type Output = ();
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let values = pool.get(self.expressions).ir(pool, output, context)?;
+
+        for (i, value) in values.iter().enumerate() {
+            context.store(output, value, &format!("%return.{}", i))?;
+        }
+
+        writeln!(output, "  ret void")?;
+        context.terminated = true;
+
+        Ok(())
+    }
+}
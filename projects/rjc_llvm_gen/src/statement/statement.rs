@@ -0,0 +1,34 @@
+use std::io;
+
+use rjc_ast::{statement::Statement, AST};
+
+use crate::{context::Context, IRGen};
+
+impl IRGen for Statement {
+    type Output = ();
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        match self {
+            Statement::VariableDeclaration((node, _)) => {
+                pool.get(*node).ir(pool, output, context)?;
+            }
+            Statement::Call((node, _)) => {
+                pool.get(*node).ir(pool, output, context)?;
+            }
+            Statement::Assignment((node, _)) => pool.get(*node).ir(pool, output, context)?,
+            Statement::If((node, _)) => pool.get(*node).ir(pool, output, context)?,
+            Statement::Return((node, _)) => pool.get(*node).ir(pool, output, context)?,
+            Statement::While((node, _)) => pool.get(*node).ir(pool, output, context)?,
+            Statement::For((node, _)) => pool.get(*node).ir(pool, output, context)?,
+            Statement::Break((node, _)) => pool.get(*node).ir(pool, output, context)?,
+            Statement::Continue((node, _)) => pool.get(*node).ir(pool, output, context)?,
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use std::io;
+
+use rjc_ast::{statement::While, AST};
+
+use crate::{
+    context::{Context, Loop},
+    IRGen,
+};
+
+impl IRGen for While {
+    type Output = ();
+
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let cond_label = format!("while.{}.cond", self.id);
+        let body_label = format!("while.{}.body", self.id);
+        let end_label = format!("while.{}.end", self.id);
+
+        context.branch(output, &cond_label)?;
+        context.label(output, &cond_label)?;
+
+        let condition = pool.get(self.condition).ir(pool, output, context)?;
+
+        writeln!(
+            output,
+            "  br i1 {}, label %{}, label %{}",
+            condition.repr, body_label, end_label
+        )?;
+        context.terminated = true;
+
+        context.label(output, &body_label)?;
+
+        context.push_loop(Loop {
+            continue_label: cond_label.clone(),
+            break_label: end_label.clone(),
+        });
+        pool.get(self.block).ir(pool, output, context)?;
+        context.pop_loop();
+
+        context.branch(output, &cond_label)?;
+        context.label(output, &end_label)?;
+
+        Ok(())
+    }
+}
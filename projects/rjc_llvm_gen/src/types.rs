@@ -0,0 +1,61 @@
+use rjc_ast::Type;
+
+pub trait LLVMType {
+    fn llvm_type(&self) -> String;
+
+    /// Whether values of the type are kept in memory and handled by pointer,
+    /// which is the case for arrays and structs. Moving them around as LLVM
+    /// aggregate values makes LLVM copy them element by element, which is
+    /// slow to compile for long arrays.
+    fn in_memory(&self) -> bool;
+
+    /// The LLVM type of a `Value` of the type, a pointer for types that are
+    /// kept in memory.
+    fn llvm_value_type(&self) -> String;
+
+    /// Width of the type in bits.
+    fn bits(&self) -> u32;
+}
+
+impl LLVMType for Type {
+    fn llvm_type(&self) -> String {
+        match self {
+            Type::I8 | Type::U8 => "i8".to_string(),
+            Type::I16 | Type::U16 => "i16".to_string(),
+            Type::I32 | Type::U32 => "i32".to_string(),
+            Type::I64 | Type::U64 | Type::USize => "i64".to_string(),
+            Type::F32 => "float".to_string(),
+            Type::F64 => "double".to_string(),
+            Type::Bool => "i1".to_string(),
+            Type::Char => "i32".to_string(),
+            Type::Str => "{ i8*, i64 }".to_string(),
+            Type::Struct(name) => format!("%struct.{}", name),
+            Type::Array(element, length) => format!("[{} x {}]", length, element.llvm_type()),
+
+            Type::Unknown => panic!("unknown type in code generation"),
+        }
+    }
+
+    fn in_memory(&self) -> bool {
+        matches!(self, Type::Array(..) | Type::Struct(_))
+    }
+
+    fn llvm_value_type(&self) -> String {
+        match self.in_memory() {
+            true => format!("{}*", self.llvm_type()),
+            false => self.llvm_type(),
+        }
+    }
+
+    fn bits(&self) -> u32 {
+        match self {
+            Type::Bool => 1,
+            Type::Char => 32,
+            Type::F32 => 32,
+            Type::F64 => 64,
+            Type::Unknown => panic!("unknown type in code generation"),
+
+            _ => self.integer_bits().expect("type without width"),
+        }
+    }
+}
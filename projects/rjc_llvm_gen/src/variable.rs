@@ -0,0 +1,39 @@
+use std::io;
+
+use rjc_ast::{ASTType, Variable, AST};
+
+use crate::{
+    context::{Context, Value},
+    IRGen,
+};
+
+impl IRGen for Variable {
+    type Output = Value;
+
+    /// Reserves the stack slot of the variable and stores its initial value,
+    /// if it has one.
+    fn ir(
+        &self,
+        pool: &AST,
+        output: &mut dyn io::Write,
+        context: &mut Context,
+    ) -> io::Result<Self::Output> {
+        let name = &pool.get(self.name).name;
+        let type_ = context.types.variable(Self::pool_ref(self.id)).clone();
+
+        let pointer = Value {
+            repr: context.alloca(name, &type_),
+            type_,
+        };
+
+        if let Some(value) = self.value {
+            let value = pool.get(value).ir(pool, output, context)?;
+
+            context.store(output, &value, &pointer.repr)?;
+        }
+
+        context.declare(Self::pool_ref(self.id), pointer.clone());
+
+        Ok(pointer)
+    }
+}
@@ -1,11 +1,12 @@
 use pest::iterators::Pair;
 
 use rjc_ast::{statement::Statement, Block, AST, ASTRef, ASTType, Symbol};
+use rjc_diagnostics::Diagnostics;
 
 use crate::{ASTParser, Rule, SymbolFromPair};
 
 impl ASTParser for Block {
-    fn parse(pool: &mut AST, pair: Pair<Rule>) -> ASTRef<Self>
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType,
     {
@@ -16,7 +17,7 @@ impl ASTParser for Block {
 
         for pair in pair.into_inner() {
             match pair.as_rule() {
-                Rule::stmt => statements.push(Statement::parse(pool, pair)),
+                Rule::stmt => statements.push(Statement::parse(pool, pair, diagnostics)),
 
                 _ => unexpected_pair!(pair),
             }
@@ -24,7 +25,7 @@ impl ASTParser for Block {
 
         let block = Block {
             id: pool.len(),
-            symbol: symbol,
+            symbol,
             statements,
         };
 
@@ -1,25 +1,30 @@
 use pest::iterators::Pair;
-use rjc_ast::{Call, AST, ASTRef, ASTType, Ident, expression::ExpressionList};
+use rjc_ast::{Call, AST, ASTRef, ASTType, Ident, Symbol, expression::ExpressionList};
+use rjc_diagnostics::Diagnostics;
 
-use crate::{ASTParser, Rule};
+use crate::{ASTParser, Rule, SymbolFromPair};
 
 impl ASTParser for Call {
     fn parse(
         pool: &mut AST,
         pair: Pair<Rule>,
+        diagnostics: &mut Diagnostics,
     ) -> ASTRef<Self>
     where
         Self: ASTType,
     {
         assert!(pair.as_rule() == Rule::func_call);
 
+        let symbol = Symbol::from_pair(&pair);
         let mut ident = None;
         let mut expressions = None;
 
         for pair in pair.into_inner() {
             match pair.as_rule() {
-                Rule::ident => ident = Some(Ident::parse(pool, pair)),
-                Rule::expr_list => expressions = Some(ExpressionList::parse(pool, pair)),
+                Rule::ident => ident = Some(Ident::parse(pool, pair, diagnostics)),
+                Rule::expr_list => {
+                    expressions = Some(ExpressionList::parse(pool, pair, diagnostics))
+                }
 
                 _ => unexpected_pair!(pair),
             }
@@ -27,6 +32,7 @@ impl ASTParser for Call {
 
         let call = Call {
             id: pool.len(),
+            symbol,
             ident: ident.expect("no ident in function call"),
             expressions: expressions.expect("no expression list in function call"),
         };
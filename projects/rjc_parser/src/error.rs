@@ -0,0 +1,171 @@
+use std::collections::BTreeSet;
+
+use pest::error::{ErrorVariant, InputLocation, LineColLocation};
+use rjc_ast::Symbol;
+use rjc_diagnostics::{codes, Diagnostic};
+
+use crate::Rule;
+
+const PUNCTUATION: &[&str] = &["{", "}", "(", ")", "]", ":", ";", ",", "="];
+
+/// Translates a pest error into a diagnostic stating what was expected in
+/// human words.
+pub fn syntax_error(input: &str, error: pest::error::Error<Rule>) -> Diagnostic {
+    let start = match error.location {
+        InputLocation::Pos(start) => start,
+        InputLocation::Span((start, _)) => start,
+    };
+
+    let found = found(&input[start..]);
+    let (line, column) = match error.line_col {
+        LineColLocation::Pos(line_col) => line_col,
+        LineColLocation::Span(line_col, _) => line_col,
+    };
+
+    let mut expected = BTreeSet::new();
+
+    if let ErrorVariant::ParsingError { positives, .. } = &error.variant {
+        expected.extend(positives.iter().filter_map(describe_rule));
+    }
+
+    if let Some(attempts) = error.parse_attempts() {
+        // Keywords, operators and characters of identifiers are already
+        // covered by the rule descriptions, only punctuation is added.
+        for token in attempts.expected_tokens() {
+            let token = token.to_string();
+
+            if PUNCTUATION.contains(&token.as_str()) {
+                expected.insert(format!("`{}`", token));
+            }
+        }
+    }
+
+    let found_description = match found {
+        "" => "end of file".to_string(),
+        "\n" | "\r" => "end of line".to_string(),
+        found => format!("`{}`", found),
+    };
+
+    let message = match expected.len() {
+        0 => format!("unexpected {}", found_description),
+        _ => format!("expected {}, found {}", join(expected), found_description),
+    };
+
+    let symbol = Symbol {
+        line,
+        column,
+        start,
+        end: start + found.len(),
+    };
+
+    Diagnostic::error(codes::SYNTAX_ERROR, message, &symbol)
+}
+
+/// Human readable description of what a rule stands for.
+fn describe_rule(rule: &Rule) -> Option<String> {
+    let description = match rule {
+        Rule::expr_elm
+        | Rule::expr_list
+        | Rule::pred_max
+        | Rule::cmp
+        | Rule::sum
+        | Rule::product
+        | Rule::logical_or
+        | Rule::logical_and
+        | Rule::unary
+        | Rule::cast
+        | Rule::int_value
+        | Rule::bit_or
+        | Rule::bit_xor
+        | Rule::bit_and
+        | Rule::shift
+        | Rule::literal
+        | Rule::int
+        | Rule::float
+        | Rule::float_value
+        | Rule::bool
+        | Rule::char
+        | Rule::string
+        | Rule::func_call
+        | Rule::postfix
+        | Rule::struct_lit
+        | Rule::array_lit
+        | Rule::array_repeat => "expression",
+        Rule::ident => "identifier",
+        Rule::stmt
+        | Rule::var_decl
+        | Rule::var_def
+        | Rule::func_ret
+        | Rule::assign
+        | Rule::place
+        | Rule::if_stmt
+        | Rule::else_if
+        | Rule::while_stmt
+        | Rule::for_stmt
+        | Rule::break_stmt
+        | Rule::continue_stmt => "statement",
+        Rule::block => "`{`",
+        Rule::var_type
+        | Rule::ret_type
+        | Rule::type_name
+        | Rule::array_type
+        | Rule::int_suffix
+        | Rule::float_suffix => "type",
+        Rule::field_value => "field",
+        Rule::array_length => "array length",
+        Rule::func => "`fn`",
+        Rule::struct_decl => "`struct`",
+        Rule::global | Rule::global_kind => "`const` or `var`",
+        Rule::doc_comment => "doc comment",
+        Rule::cmp_eq
+        | Rule::cmp_ne
+        | Rule::cmp_lt
+        | Rule::cmp_le
+        | Rule::cmp_gt
+        | Rule::cmp_ge
+        | Rule::add
+        | Rule::sub
+        | Rule::mul
+        | Rule::div
+        | Rule::rem
+        | Rule::or
+        | Rule::and
+        | Rule::bit_or_op
+        | Rule::bit_xor_op
+        | Rule::bit_and_op
+        | Rule::shl
+        | Rule::shr
+        | Rule::range_inclusive
+        | Rule::range_exclusive => "operator",
+        Rule::EOI => "end of file",
+
+        _ => return None,
+    };
+
+    Some(description.to_string())
+}
+
+/// The token at the start of `input`, used to tell what was found instead
+/// of what was expected.
+fn found(input: &str) -> &str {
+    let input = input.trim_end();
+
+    let word = input
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(input.len());
+
+    match word {
+        0 => input.chars().next().map_or("", |c| &input[..c.len_utf8()]),
+        word => &input[..word],
+    }
+}
+
+fn join(expected: BTreeSet<String>) -> String {
+    let expected: Vec<String> = expected.into_iter().collect();
+
+    match expected.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,60 @@
+use pest::iterators::Pair;
+
+use rjc_ast::{
+    expression::{ArrayLiteral, ArrayRepeat, Expression},
+    AST, ASTRef, ASTType, Symbol,
+};
+use rjc_diagnostics::Diagnostics;
+
+use crate::{types::array_length, ASTParser, Rule, SymbolFromPair};
+
+impl ASTParser for ArrayLiteral {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(pair.as_rule() == Rule::array_lit);
+
+        let symbol = Symbol::from_pair(&pair);
+        let elements = pair
+            .into_inner()
+            .map(|pair| Expression::parse(pool, pair, diagnostics))
+            .collect();
+
+        let literal = ArrayLiteral {
+            id: pool.len(),
+            symbol,
+            elements,
+        };
+
+        pool.add(literal)
+    }
+}
+
+impl ASTParser for ArrayRepeat {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(pair.as_rule() == Rule::array_repeat);
+
+        let symbol = Symbol::from_pair(&pair);
+        let mut pairs = pair.into_inner();
+
+        let value = Expression::parse(
+            pool,
+            pairs.next().expect("no value in array"),
+            diagnostics,
+        );
+        let length = array_length(pairs.next().expect("no length in array"), diagnostics);
+
+        let repeat = ArrayRepeat {
+            id: pool.len(),
+            symbol,
+            value,
+            length,
+        };
+
+        pool.add(repeat)
+    }
+}
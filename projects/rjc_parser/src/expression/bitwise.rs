@@ -0,0 +1,65 @@
+use pest::iterators::Pair;
+
+use rjc_ast::{
+    expression::{Bitwise, BitwiseOp, Expression},
+    ASTRef, ASTType, Symbol, AST,
+};
+use rjc_diagnostics::Diagnostics;
+
+use crate::{ASTParser, Rule, SymbolFromPair};
+
+impl ASTParser for Bitwise {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(matches!(
+            pair.as_rule(),
+            Rule::bit_or | Rule::bit_xor | Rule::bit_and | Rule::shift
+        ));
+
+        let symbol = Symbol::from_pair(&pair);
+        let mut pairs = pair.into_inner();
+
+        let first = Expression::parse(
+            pool,
+            pairs
+                .next()
+                .expect("no first expression in bitwise expression"),
+            diagnostics,
+        );
+
+        let mut rest = vec![];
+
+        while let Some(op_pair) = pairs.next() {
+            let expression = Expression::parse(
+                pool,
+                pairs
+                    .next()
+                    .expect("not enough expressions in bitwise expression"),
+                diagnostics,
+            );
+
+            let bitwise_op = match op_pair.as_rule() {
+                Rule::bit_or_op => BitwiseOp::Or(expression),
+                Rule::bit_xor_op => BitwiseOp::Xor(expression),
+                Rule::bit_and_op => BitwiseOp::And(expression),
+                Rule::shl => BitwiseOp::Shl(expression),
+                Rule::shr => BitwiseOp::Shr(expression),
+
+                _ => unexpected_pair!(op_pair),
+            };
+
+            rest.push(bitwise_op);
+        }
+
+        let bitwise = Bitwise {
+            id: pool.len(),
+            symbol,
+            first,
+            rest,
+        };
+
+        pool.add(bitwise)
+    }
+}
@@ -0,0 +1,57 @@
+use pest::iterators::Pair;
+
+use rjc_ast::{
+    expression::{Cast, Expression},
+    AST, ASTRef, ASTType, Symbol, Type,
+};
+use rjc_diagnostics::Diagnostics;
+
+use crate::{ASTParser, Rule, SymbolFromPair};
+
+impl ASTParser for Cast {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(pair.as_rule() == Rule::cast);
+
+        let mut symbol = Symbol::from_pair(&pair);
+        let mut pairs = pair.into_inner();
+
+        let mut expression = Expression::parse(
+            pool,
+            pairs.next().expect("no expression in cast"),
+            diagnostics,
+        );
+
+        let types: Vec<_> = pairs.collect();
+        let (last, inner) = types.split_last().expect("no type in cast");
+
+        // `a as i64 as u8` converts in order, so every cast wraps the
+        // previous one.
+        for type_pair in inner {
+            symbol.end = type_pair.as_span().end();
+
+            let cast = Cast {
+                id: pool.len(),
+                symbol: symbol.clone(),
+                expression,
+                type_: Type::from_str(type_pair.as_str()),
+            };
+
+            let cast = pool.add(cast);
+            expression = pool.add(Expression::Cast((cast, pool.len())));
+        }
+
+        symbol.end = last.as_span().end();
+
+        let cast = Cast {
+            id: pool.len(),
+            symbol,
+            expression,
+            type_: Type::from_str(last.as_str()),
+        };
+
+        pool.add(cast)
+    }
+}
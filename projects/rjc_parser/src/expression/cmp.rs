@@ -2,10 +2,11 @@ use pest::iterators::Pair;
 
 use rjc_ast::{
     expression::{Cmp, CmpOp, Expression},
-    AST, ASTRef, ASTType,
+    AST, ASTRef, ASTType, Symbol,
 };
+use rjc_diagnostics::Diagnostics;
 
-use crate::{ASTParser, Rule};
+use crate::{ASTParser, Rule, SymbolFromPair};
 
 fn cmp_op_from_pair(pair: Pair<Rule>) -> CmpOp {
     match pair.as_rule() {
@@ -21,18 +22,19 @@ fn cmp_op_from_pair(pair: Pair<Rule>) -> CmpOp {
 }
 
 impl ASTParser for Cmp {
-    fn parse(pool: &mut AST, pair: Pair<Rule>) -> ASTRef<Self>
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType,
     {
         assert!(pair.as_rule() == Rule::cmp);
 
+        let symbol = Symbol::from_pair(&pair);
         let mut expressions = vec![];
         let mut cmp_op = None;
 
         for pair in pair.into_inner() {
             match pair.as_rule() {
-                Rule::pred_1 => expressions.push(Expression::parse(pool, pair)),
+                Rule::bit_or => expressions.push(Expression::parse(pool, pair, diagnostics)),
 
                 _ => cmp_op = Some(cmp_op_from_pair(pair)),
             }
@@ -48,6 +50,7 @@ impl ASTParser for Cmp {
 
         let cmp = Cmp {
             id: pool.len(),
+            symbol,
             op: cmp_op.expect("no compare operation in compare expression"),
             left,
             right,
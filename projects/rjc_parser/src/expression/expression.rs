@@ -1,14 +1,20 @@
 use pest::iterators::Pair;
 
 use rjc_ast::{
-    expression::{Cmp, Expression, Literal, Sum},
+    expression::{
+        ArrayLiteral, ArrayRepeat, Bitwise, Cast, Cmp, Expression, Literal, Logical, Product,
+        StructLiteral, Sum, Unary,
+    },
     Call, Ident, AST, ASTRef, ASTType,
 };
+use rjc_diagnostics::Diagnostics;
 
 use crate::{ASTParser, Rule};
 
+use super::postfix::postfix;
+
 impl ASTParser for Expression {
-    fn parse(pool: &mut AST, mut pair: Pair<Rule>) -> ASTRef<Self>
+    fn parse(pool: &mut AST, mut pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType,
     {
@@ -19,11 +25,40 @@ impl ASTParser for Expression {
         let pair = unpred(pair);
 
         let expression = match pair.as_rule() {
-            Rule::literal => Expression::Literal((Literal::parse(pool, pair), pool.len())),
-            Rule::ident => Expression::Ident((Ident::parse(pool, pair), pool.len())),
-            Rule::func_call => Expression::Call((Call::parse(pool, pair), pool.len())),
-            Rule::cmp => Expression::Cmp((Cmp::parse(pool, pair), pool.len())),
-            Rule::sum => Expression::Sum((Sum::parse(pool, pair), pool.len())),
+            Rule::literal => {
+                Expression::Literal((Literal::parse(pool, pair, diagnostics), pool.len()))
+            }
+            Rule::ident => Expression::Ident((Ident::parse(pool, pair, diagnostics), pool.len())),
+            Rule::func_call => Expression::Call((Call::parse(pool, pair, diagnostics), pool.len())),
+            Rule::cmp => Expression::Cmp((Cmp::parse(pool, pair, diagnostics), pool.len())),
+            Rule::sum => Expression::Sum((Sum::parse(pool, pair, diagnostics), pool.len())),
+            Rule::product => {
+                Expression::Product((Product::parse(pool, pair, diagnostics), pool.len()))
+            }
+            Rule::logical_or | Rule::logical_and => {
+                Expression::Logical((Logical::parse(pool, pair, diagnostics), pool.len()))
+            }
+            Rule::bit_or | Rule::bit_xor | Rule::bit_and | Rule::shift => {
+                Expression::Bitwise((Bitwise::parse(pool, pair, diagnostics), pool.len()))
+            }
+            Rule::cast => Expression::Cast((Cast::parse(pool, pair, diagnostics), pool.len())),
+            Rule::unary => Expression::Unary((Unary::parse(pool, pair, diagnostics), pool.len())),
+            Rule::postfix | Rule::place => postfix(pool, pair, diagnostics),
+            Rule::struct_lit => {
+                let literal = StructLiteral::parse(pool, pair, diagnostics);
+
+                Expression::StructLiteral((literal, pool.len()))
+            }
+            Rule::array_lit => {
+                let literal = ArrayLiteral::parse(pool, pair, diagnostics);
+
+                Expression::ArrayLiteral((literal, pool.len()))
+            }
+            Rule::array_repeat => {
+                let repeat = ArrayRepeat::parse(pool, pair, diagnostics);
+
+                Expression::ArrayRepeat((repeat, pool.len()))
+            }
 
             _ => unexpected_pair!(pair),
         };
@@ -32,17 +67,31 @@ impl ASTParser for Expression {
     }
 }
 
+/// Skips the rules that only wrap a single expression: `pred_max`, which
+/// also holds parenthesized expressions, and operator rules without an
+/// operator.
 fn unpred(pair: Pair<Rule>) -> Pair<Rule> {
     match pair.as_rule() {
-        Rule::pred_0 => (),
-        Rule::pred_1 => (),
-        Rule::pred_2 => (),
         Rule::pred_max => (),
+        Rule::logical_or
+        | Rule::logical_and
+        | Rule::cmp
+        | Rule::bit_or
+        | Rule::bit_xor
+        | Rule::bit_and
+        | Rule::shift
+        | Rule::sum
+        | Rule::product
+        | Rule::unary
+        | Rule::cast
+        | Rule::postfix
+        | Rule::place
+            if pair.clone().into_inner().count() == 1 => (),
 
         _ => return pair,
     }
 
     let pair = pair.into_inner().next().expect("no child in pred");
 
-    return unpred(pair);
+    unpred(pair)
 }
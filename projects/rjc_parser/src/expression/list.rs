@@ -4,11 +4,12 @@ use rjc_ast::{
     expression::{Expression, ExpressionList},
     AST, ASTRef, ASTType, Symbol,
 };
+use rjc_diagnostics::Diagnostics;
 
 use crate::{ASTParser, Rule, SymbolFromPair};
 
 impl ASTParser for ExpressionList {
-    fn parse(pool: &mut AST, pair: Pair<Rule>) -> ASTRef<Self>
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType,
     {
@@ -19,7 +20,7 @@ impl ASTParser for ExpressionList {
 
         for pair in pair.into_inner() {
             match pair.as_rule() {
-                Rule::expr_elm => expressions.push(Expression::parse(pool, pair)),
+                Rule::expr_elm => expressions.push(Expression::parse(pool, pair, diagnostics)),
 
                 _ => unexpected_pair!(pair),
             }
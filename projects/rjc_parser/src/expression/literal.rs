@@ -1,23 +1,249 @@
 use pest::iterators::Pair;
 
-use rjc_ast::{expression::Literal, AST, ASTRef, ASTType, Symbol};
+use rjc_ast::{
+    expression::{Literal, LiteralValue},
+    AST, ASTRef, ASTType, Symbol, Type,
+};
+use rjc_diagnostics::{codes, Diagnostic, Diagnostics};
 
 use crate::{ASTParser, Rule, SymbolFromPair};
 
 impl ASTParser for Literal {
-    fn parse(pool: &mut AST, pair: Pair<Rule>) -> ASTRef<Self>
+    /// Numbers that do not fit into their suffix type, or into any integer
+    /// or float type without a suffix, are reported as out of range, integers
+    /// without digits or with digits invalid for their base as malformed.
+    /// Unknown escapes in strings and characters are reported and left out,
+    /// as are character literals without exactly one character.
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType,
     {
         assert!(pair.as_rule() == Rule::literal);
 
         let symbol = Symbol::from_pair(&pair);
+        let inner = pair.into_inner().next().expect("no value in literal");
+
+        let value = match inner.as_rule() {
+            Rule::int => {
+                let (value, suffix) = number(inner);
+                let (min, max) = match &suffix {
+                    Some(suffix) => suffix.integer_range().expect("integer suffix"),
+                    None => (i64::MIN as i128, u64::MAX as i128),
+                };
+
+                let value = match integer(&value) {
+                    Ok(Some(value)) if min <= value && value <= max => value,
+                    Ok(_) => {
+                        let range = Some((min, max));
+
+                        out_of_range(diagnostics, &symbol, "integer", suffix.as_ref(), range);
+                        0
+                    }
+                    Err(message) => {
+                        diagnostics.push(Diagnostic::error(
+                            codes::MALFORMED_NUMBER,
+                            message,
+                            &symbol,
+                        ));
+                        0
+                    }
+                };
+
+                LiteralValue::Integer { value, suffix }
+            }
+            Rule::float => {
+                let (value, suffix) = number(inner);
+
+                let finite = match suffix {
+                    Some(Type::F32) => value.parse::<f32>().is_ok_and(f32::is_finite),
+                    _ => value.parse::<f64>().is_ok_and(f64::is_finite),
+                };
+
+                let value = if finite {
+                    value.parse::<f64>().expect("invalid float literal")
+                } else {
+                    out_of_range(diagnostics, &symbol, "float", suffix.as_ref(), None);
+                    0.0
+                };
+
+                LiteralValue::Float { value, suffix }
+            }
+            Rule::bool => LiteralValue::Bool(inner.as_str() == "true"),
+            Rule::char => LiteralValue::Char(character(inner, diagnostics)),
+            Rule::string => LiteralValue::Str(text(inner, diagnostics)),
+
+            _ => unexpected_pair!(inner),
+        };
+
         let literal = Literal {
             id: pool.len(),
             symbol,
-            value: pair.as_str().to_string(),
+            value,
         };
 
         pool.add(literal)
     }
 }
+
+/// Splits a number into its digits and its optional type suffix.
+fn number(pair: Pair<Rule>) -> (String, Option<Type>) {
+    let mut value = String::new();
+    let mut suffix = None;
+
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::int_value | Rule::float_value => value = pair.as_str().to_string(),
+            Rule::int_suffix | Rule::float_suffix => suffix = Some(Type::from_str(pair.as_str())),
+
+            _ => unexpected_pair!(pair),
+        }
+    }
+
+    (value, suffix)
+}
+
+/// The value of an integer literal, which may have a `0x`, `0o` or `0b`
+/// prefix and `_` separators. `None` if it does not even fit into an `i128`.
+fn integer(text: &str) -> Result<Option<i128>, String> {
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+
+    let (radix, digits) = match text.get(..2) {
+        Some("0x") => (16, &text[2..]),
+        Some("0o") => (8, &text[2..]),
+        Some("0b") => (2, &text[2..]),
+        _ => (10, text),
+    };
+
+    let mut value: Option<i128> = None;
+
+    for c in digits.chars().filter(|c| *c != '_') {
+        let digit = c
+            .to_digit(radix)
+            .ok_or_else(|| format!("invalid digit `{}` in base {} literal", c, radix))?;
+
+        let next = value
+            .unwrap_or(0)
+            .checked_mul(radix as i128)
+            .and_then(|value| value.checked_add(digit as i128));
+
+        match next {
+            Some(next) => value = Some(next),
+            None => return Ok(None),
+        }
+    }
+
+    match value {
+        Some(value) if negative => Ok(Some(-value)),
+        Some(value) => Ok(Some(value)),
+        None => Err("no valid digits found for number".to_string()),
+    }
+}
+
+/// The character of a character literal, which holds exactly one character
+/// or escape.
+fn character(pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> char {
+    let symbol = Symbol::from_pair(&pair);
+
+    let diagnostic = match pair.clone().into_inner().count() {
+        0 => Diagnostic::error(
+            codes::INVALID_CHAR_LITERAL,
+            "empty character literal".to_string(),
+            &symbol,
+        ),
+        1 => return text(pair, diagnostics).chars().next().unwrap_or_default(),
+        _ => Diagnostic::error(
+            codes::INVALID_CHAR_LITERAL,
+            "character literal may only contain one character".to_string(),
+            &symbol,
+        )
+        .with_note("use a string literal for text, `\"...\"`".to_string()),
+    };
+
+    diagnostics.push(diagnostic);
+
+    char::default()
+}
+
+/// The text of a string or character literal with its escapes replaced.
+fn text(pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> String {
+    let mut value = String::new();
+
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::string_text | Rule::char_text => value.push_str(pair.as_str()),
+            Rule::escape => match escape(pair.as_str()) {
+                Ok(c) => value.push(c),
+                Err(message) => {
+                    let diagnostic = Diagnostic::error(
+                        codes::INVALID_ESCAPE,
+                        message,
+                        &Symbol::from_pair(&pair),
+                    )
+                    .with_note(
+                        "valid escapes are `\\n`, `\\t`, `\\\"`, `\\'`, `\\\\` and `\\u{...}`"
+                            .to_string(),
+                    );
+
+                    diagnostics.push(diagnostic);
+                }
+            },
+
+            _ => unexpected_pair!(pair),
+        }
+    }
+
+    value
+}
+
+/// The character an escape sequence like `\n` or `\u{e9}` stands for.
+fn escape(escape: &str) -> Result<char, String> {
+    let unicode = match escape {
+        "\\n" => return Ok('\n'),
+        "\\t" => return Ok('\t'),
+        "\\\"" => return Ok('"'),
+        "\\'" => return Ok('\''),
+        "\\\\" => return Ok('\\'),
+        _ => escape
+            .strip_prefix("\\u{")
+            .and_then(|unicode| unicode.strip_suffix('}')),
+    };
+
+    let digits = match unicode {
+        Some(digits) => digits,
+        None => return Err(format!("unknown character escape `{}`", escape)),
+    };
+
+    if digits.is_empty() || digits.len() > 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err("unicode escape must have 1 to 6 hex digits".to_string());
+    }
+
+    u32::from_str_radix(digits, 16)
+        .ok()
+        .and_then(char::from_u32)
+        .ok_or_else(|| format!("invalid unicode character escape `{}`", escape))
+}
+
+fn out_of_range(
+    diagnostics: &mut Diagnostics,
+    symbol: &Symbol,
+    kind: &str,
+    suffix: Option<&Type>,
+    range: Option<(i128, i128)>,
+) {
+    let message = match suffix {
+        Some(suffix) => format!("literal out of range for `{}`", suffix),
+        None => format!("{} literal is too large", kind),
+    };
+
+    let mut diagnostic = Diagnostic::error(codes::LITERAL_OUT_OF_RANGE, message, symbol);
+
+    if let (Some(suffix), Some((min, max))) = (suffix, range) {
+        diagnostic = diagnostic
+            .with_note(format!("the range of `{}` is `{}..={}`", suffix, min, max));
+    }
+
+    diagnostics.push(diagnostic);
+}
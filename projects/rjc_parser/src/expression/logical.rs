@@ -0,0 +1,60 @@
+use pest::iterators::Pair;
+
+use rjc_ast::{
+    expression::{Expression, Logical, LogicalOp},
+    AST, ASTRef, ASTType, Symbol,
+};
+use rjc_diagnostics::Diagnostics;
+
+use crate::{ASTParser, Rule, SymbolFromPair};
+
+impl ASTParser for Logical {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(matches!(
+            pair.as_rule(),
+            Rule::logical_or | Rule::logical_and
+        ));
+
+        let symbol = Symbol::from_pair(&pair);
+        let mut pairs = pair.into_inner();
+
+        let first = Expression::parse(
+            pool,
+            pairs.next().expect("no first expression in logical expression"),
+            diagnostics,
+        );
+
+        let mut rest = vec![];
+
+        while let Some(op_pair) = pairs.next() {
+            let expression = Expression::parse(
+                pool,
+                pairs
+                    .next()
+                    .expect("not enough expressions in logical expression"),
+                diagnostics,
+            );
+
+            let logical_op = match op_pair.as_rule() {
+                Rule::and => LogicalOp::And(expression),
+                Rule::or => LogicalOp::Or(expression),
+
+                _ => unexpected_pair!(op_pair),
+            };
+
+            rest.push(logical_op);
+        }
+
+        let logical = Logical {
+            id: pool.len(),
+            symbol,
+            first,
+            rest,
+        };
+
+        pool.add(logical)
+    }
+}
@@ -1,5 +1,13 @@
 mod list;
+#[allow(clippy::module_inception)]
 mod expression;
 mod literal;
 mod cmp;
-mod sum;
\ No newline at end of file
+mod sum;mod product;
+mod logical;
+mod unary;
+mod bitwise;
+mod cast;
+mod array;
+mod postfix;
+mod struct_literal;
@@ -0,0 +1,81 @@
+use pest::iterators::Pair;
+
+use rjc_ast::{
+    expression::{Expression, Field, Index},
+    ASTRef, Ident, AST, Symbol,
+};
+use rjc_diagnostics::Diagnostics;
+
+use crate::{ASTParser, Rule, SymbolFromPair};
+
+/// Parses field accesses and indexing, `a.b[i].c`. They apply in order, so
+/// every access wraps the previous one. Returns the outermost access, which
+/// is left for the caller to add to the pool.
+pub fn postfix(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> Expression {
+    assert!(matches!(pair.as_rule(), Rule::postfix | Rule::place));
+
+    let mut symbol = Symbol::from_pair(&pair);
+    let mut pairs = pair.into_inner();
+
+    let mut expression = Expression::parse(
+        pool,
+        pairs.next().expect("no expression in postfix expression"),
+        diagnostics,
+    );
+
+    let suffixes: Vec<_> = pairs.collect();
+    let (last, inner) = suffixes.split_last().expect("no suffix in postfix expression");
+
+    for suffix in inner {
+        let access = access(pool, suffix.clone(), &mut symbol, expression, diagnostics);
+
+        expression = pool.add(access);
+    }
+
+    access(pool, last.clone(), &mut symbol, expression, diagnostics)
+}
+
+/// Applies a single field or index suffix to `expression`.
+fn access(
+    pool: &mut AST,
+    suffix: Pair<Rule>,
+    symbol: &mut Symbol,
+    expression: ASTRef<Expression>,
+    diagnostics: &mut Diagnostics,
+) -> Expression {
+    symbol.end = suffix.as_span().end();
+
+    let rule = suffix.as_rule();
+    let inner = suffix.into_inner().next().expect("no pair in suffix");
+
+    match rule {
+        Rule::field_suffix => {
+            let field = Ident::parse(pool, inner, diagnostics);
+            let field = Field {
+                id: pool.len(),
+                symbol: symbol.clone(),
+                expression,
+                field,
+            };
+
+            let field = pool.add(field);
+
+            Expression::Field((field, pool.len()))
+        }
+        Rule::index_suffix => {
+            let index = Expression::parse(pool, inner, diagnostics);
+            let index = Index {
+                id: pool.len(),
+                symbol: symbol.clone(),
+                expression,
+                index,
+            };
+
+            let index = pool.add(index);
+
+            Expression::Index((index, pool.len()))
+        }
+
+        _ => unexpected_pair!(inner),
+    }
+}
@@ -0,0 +1,56 @@
+use pest::iterators::Pair;
+
+use rjc_ast::{
+    expression::{Expression, Product, ProductOp},
+    AST, ASTRef, ASTType, Symbol,
+};
+use rjc_diagnostics::Diagnostics;
+
+use crate::{ASTParser, Rule, SymbolFromPair};
+
+impl ASTParser for Product {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(pair.as_rule() == Rule::product);
+
+        let symbol = Symbol::from_pair(&pair);
+        let mut pairs = pair.into_inner();
+
+        let first = Expression::parse(
+            pool,
+            pairs.next().expect("no first expression in product"),
+            diagnostics,
+        );
+
+        let mut rest = vec![];
+
+        while let Some(op_pair) = pairs.next() {
+            let expression = Expression::parse(
+                pool,
+                pairs.next().expect("not enough expressions in product"),
+                diagnostics,
+            );
+
+            let product_op = match op_pair.as_rule() {
+                Rule::mul => ProductOp::Mul(expression),
+                Rule::div => ProductOp::Div(expression),
+                Rule::rem => ProductOp::Rem(expression),
+
+                _ => unexpected_pair!(op_pair),
+            };
+
+            rest.push(product_op);
+        }
+
+        let product = Product {
+            id: pool.len(),
+            symbol,
+            first,
+            rest,
+        };
+
+        pool.add(product)
+    }
+}
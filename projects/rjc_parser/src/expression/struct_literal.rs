@@ -0,0 +1,72 @@
+use pest::iterators::Pair;
+
+use rjc_ast::{
+    expression::{Expression, FieldValue, StructLiteral},
+    Ident, AST, ASTRef, ASTType, Symbol,
+};
+use rjc_diagnostics::Diagnostics;
+
+use crate::{ASTParser, Rule, SymbolFromPair};
+
+impl ASTParser for StructLiteral {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(pair.as_rule() == Rule::struct_lit);
+
+        let symbol = Symbol::from_pair(&pair);
+        let mut name = None;
+        let mut fields = vec![];
+
+        for pair in pair.into_inner() {
+            match pair.as_rule() {
+                Rule::ident => name = Some(Ident::parse(pool, pair, diagnostics)),
+                Rule::field_value => fields.push(FieldValue::parse(pool, pair, diagnostics)),
+
+                _ => unexpected_pair!(pair),
+            }
+        }
+
+        let literal = StructLiteral {
+            id: pool.len(),
+            symbol,
+            ident: name.expect("no name in struct literal"),
+            fields,
+        };
+
+        pool.add(literal)
+    }
+}
+
+impl ASTParser for FieldValue {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(pair.as_rule() == Rule::field_value);
+
+        let symbol = Symbol::from_pair(&pair);
+        let mut pairs = pair.into_inner();
+
+        let ident = Ident::parse(
+            pool,
+            pairs.next().expect("no name in field value"),
+            diagnostics,
+        );
+        let expression = Expression::parse(
+            pool,
+            pairs.next().expect("no expression in field value"),
+            diagnostics,
+        );
+
+        let field_value = FieldValue {
+            id: pool.len(),
+            symbol,
+            ident,
+            expression,
+        };
+
+        pool.add(field_value)
+    }
+}
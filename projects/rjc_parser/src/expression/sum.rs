@@ -2,23 +2,26 @@ use pest::iterators::Pair;
 
 use rjc_ast::{
     expression::{Expression, Sum, SumOp},
-    AST, ASTRef, ASTType,
+    AST, ASTRef, ASTType, Symbol,
 };
+use rjc_diagnostics::Diagnostics;
 
-use crate::{ASTParser, Rule};
+use crate::{ASTParser, Rule, SymbolFromPair};
 
 impl ASTParser for Sum {
-    fn parse(pool: &mut AST, pair: Pair<Rule>) -> ASTRef<Self>
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType,
     {
         assert!(pair.as_rule() == Rule::sum);
 
+        let symbol = Symbol::from_pair(&pair);
         let mut pairs = pair.into_inner();
 
         let first = Expression::parse(
             pool,
             pairs.next().expect("no first expression in summation"),
+            diagnostics,
         );
 
         let mut rest = vec![];
@@ -27,6 +30,7 @@ impl ASTParser for Sum {
             let expression = Expression::parse(
                 pool,
                 pairs.next().expect("not enough expressions in summation"),
+                diagnostics,
             );
 
             let sum_op = match op_pair.as_rule() {
@@ -41,6 +45,7 @@ impl ASTParser for Sum {
 
         let sum = Sum {
             id: pool.len(),
+            symbol,
             first,
             rest,
         };
@@ -0,0 +1,46 @@
+use pest::iterators::Pair;
+
+use rjc_ast::{
+    expression::{Expression, Unary, UnaryOp},
+    AST, ASTRef, ASTType, Symbol,
+};
+use rjc_diagnostics::Diagnostics;
+
+use crate::{ASTParser, Rule, SymbolFromPair};
+
+impl ASTParser for Unary {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(pair.as_rule() == Rule::unary);
+
+        let symbol = Symbol::from_pair(&pair);
+        let mut pairs = pair.into_inner();
+
+        let op_pair = pairs.next().expect("no operator in unary expression");
+
+        let op = match op_pair.as_rule() {
+            Rule::not => UnaryOp::Not,
+            Rule::neg => UnaryOp::Neg,
+            Rule::bit_not => UnaryOp::BitNot,
+
+            _ => unexpected_pair!(op_pair),
+        };
+
+        let expression = Expression::parse(
+            pool,
+            pairs.next().expect("no expression in unary expression"),
+            diagnostics,
+        );
+
+        let unary = Unary {
+            id: pool.len(),
+            symbol,
+            op,
+            expression,
+        };
+
+        pool.add(unary)
+    }
+}
@@ -1,11 +1,12 @@
 use pest::iterators::Pair;
 
-use rjc_ast::{Block, Function, Ident, AST, ASTRef, ASTType, Symbol, Type, TypeList, Variable};
+use rjc_ast::{Block, Function, Ident, AST, ASTRef, ASTType, Symbol, TypeList, Variable};
+use rjc_diagnostics::Diagnostics;
 
-use crate::{ASTParser, Rule, SymbolFromPair};
+use crate::{types::parse_type, ASTParser, Rule, SymbolFromPair};
 
 impl ASTParser for Function {
-    fn parse(pool: &mut AST, pair: Pair<Rule>) -> ASTRef<Self>
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType,
     {
@@ -16,13 +17,15 @@ impl ASTParser for Function {
         let mut args = vec![];
         let mut block = None;
         let mut return_type = vec![];
+        let mut doc = vec![];
 
         for pair in pair.into_inner() {
             match pair.as_rule() {
-                Rule::ident => name = Some(Ident::parse(pool, pair)),
-                Rule::var_decl => args.push(Variable::parse(pool, pair)),
-                Rule::block => block = Some(Block::parse(pool, pair)),
-                Rule::ret_type => return_type.push(Type::from_str(pair.as_str())),
+                Rule::ident => name = Some(Ident::parse(pool, pair, diagnostics)),
+                Rule::var_decl => args.push(Variable::parse(pool, pair, diagnostics)),
+                Rule::block => block = Some(Block::parse(pool, pair, diagnostics)),
+                Rule::ret_type => return_type.push(parse_type(pair, diagnostics)),
+                Rule::doc_comment => doc.push(doc_line(pair.as_str())),
 
                 _ => unexpected_pair!(pair),
             }
@@ -35,8 +38,17 @@ impl ASTParser for Function {
             args,
             block: block.expect("no block defined"),
             return_type: TypeList { list: return_type },
+            doc,
         };
 
         pool.add(function)
     }
 }
+
+/// Strips the `///` and the space following it from a doc comment.
+fn doc_line(comment: &str) -> String {
+    let line = comment.strip_prefix("///").unwrap_or(comment);
+    let line = line.strip_prefix(' ').unwrap_or(line);
+
+    line.trim_end().to_string()
+}
@@ -0,0 +1,40 @@
+use pest::iterators::Pair;
+
+use rjc_ast::{expression::Expression, Global, AST, ASTRef, ASTType, Symbol, Variable};
+use rjc_diagnostics::Diagnostics;
+
+use crate::{ASTParser, Rule, SymbolFromPair};
+
+impl ASTParser for Global {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(pair.as_rule() == Rule::global);
+
+        let symbol = Symbol::from_pair(&pair);
+        let mut pairs = pair.into_inner();
+
+        let constant = pairs.next().expect("no kind for global").as_str() == "const";
+        let variable = Variable::parse(
+            pool,
+            pairs.next().expect("no declaration for global"),
+            diagnostics,
+        );
+        let value = Expression::parse(
+            pool,
+            pairs.next().expect("no value for global"),
+            diagnostics,
+        );
+
+        let global = Global {
+            id: pool.len(),
+            symbol,
+            constant,
+            variable,
+            value,
+        };
+
+        pool.add(global)
+    }
+}
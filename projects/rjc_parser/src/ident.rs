@@ -1,11 +1,12 @@
 use pest::iterators::Pair;
 
 use rjc_ast::{Ident, AST, ASTRef, ASTType, Symbol};
+use rjc_diagnostics::Diagnostics;
 
 use crate::{ASTParser, Rule, SymbolFromPair};
 
 impl ASTParser for Ident {
-    fn parse(pool: &mut AST, pair: Pair<Rule>) -> ASTRef<Self>
+    fn parse(pool: &mut AST, pair: Pair<Rule>, _: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType,
     {
@@ -16,21 +16,26 @@ macro_rules! unexpected_pair {
 
 mod block;
 mod call;
+mod error;
 mod expression;
 mod function;
+mod global;
 mod ident;
 mod module;
 mod statement;
+mod struct_;
 mod symbol;
+mod types;
 mod variable;
 
 pub use module::from_file;
 use rjc_ast::{AST, ASTRef, ASTType};
+use rjc_diagnostics::Diagnostics;
 
 pub use symbol::SymbolFromPair;
 
 trait ASTParser {
-    fn parse(pool: &mut AST, pair: Pair<Rule>) -> ASTRef<Self>
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType;
 }
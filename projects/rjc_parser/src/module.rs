@@ -1,27 +1,38 @@
-use std::{io, fs};
+use std::{fs, io};
 
 use pest::{iterators::Pair, Parser as _};
-use rjc_ast::{AST, ASTRef, ASTType, Function, Module};
+use rjc_ast::{AST, ASTRef, ASTType, Function, Global, Module, Struct};
+use rjc_diagnostics::Diagnostics;
 
-use crate::{Rule, ASTParser, Parser};
+use crate::{error::syntax_error, Rule, ASTParser, Parser};
 
-pub fn from_file(path: String) -> io::Result<AST> {
+/// Parses a file into an AST. Syntax errors are pushed to `diagnostics`, in
+/// which case the returned AST holds the source but no module.
+pub fn from_file(path: String, diagnostics: &mut Diagnostics) -> io::Result<AST> {
     let input = fs::read_to_string(&path)?;
 
     let mut pool = AST::new(path, input.clone());
 
-    let pair = Parser::parse(Rule::module, input.as_str())
-        .unwrap()
-        .next()
-        .unwrap();
+    // Track the tokens tried at the error position for the error message
+    pest::set_error_detail(true);
 
-    let _ = Module::parse(&mut pool, pair);
+    let pair = match Parser::parse(Rule::module, input.as_str()) {
+        Ok(mut pairs) => pairs.next().expect("no module pair"),
+        Err(error) => {
+            diagnostics.push(syntax_error(&input, error));
+
+            return Ok(pool);
+        }
+    };
+
+    let module = Module::parse(&mut pool, pair, diagnostics);
+    pool.set_module(module);
 
     Ok(pool)
 }
 
 impl ASTParser for Module {
-    fn parse(pool: &mut AST, pair: Pair<Rule>) -> ASTRef<Self>
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType + Sized,
     {
@@ -31,12 +42,22 @@ impl ASTParser for Module {
             _ => unexpected_pair!(pair),
         };
 
+        let mut structs = vec![];
+        let mut globals = vec![];
         let mut functions = vec![];
 
         for pair in inner {
             match pair.as_rule() {
+                Rule::struct_decl => {
+                    let struct_ = Struct::parse(pool, pair, diagnostics);
+                    structs.push(struct_);
+                },
+                Rule::global => {
+                    let global = Global::parse(pool, pair, diagnostics);
+                    globals.push(global);
+                },
                 Rule::func => {
-                    let function = Function::parse(pool, pair);
+                    let function = Function::parse(pool, pair, diagnostics);
                     functions.push(function);
                 },
                 Rule::EOI => break,
@@ -47,6 +68,8 @@ impl ASTParser for Module {
 
         pool.add(Self {
             id: pool.len(),
+            structs,
+            globals,
             functions,
         })
     }
@@ -1,11 +1,16 @@
 use pest::iterators::Pair;
 
-use rjc_ast::{expression::ExpressionList, statement::Assignment, Ident, AST, ASTRef, ASTType};
+use rjc_ast::{
+    expression::{Expression, ExpressionList},
+    statement::Assignment,
+    AST, ASTRef, ASTType,
+};
+use rjc_diagnostics::Diagnostics;
 
 use crate::{ASTParser, Rule};
 
 impl ASTParser for Assignment {
-    fn parse(pool: &mut AST, pair: Pair<Rule>) -> ASTRef<Self>
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType,
     {
@@ -16,8 +21,10 @@ impl ASTParser for Assignment {
 
         for pair in pair.into_inner() {
             match pair.as_rule() {
-                Rule::ident => targets.push(Ident::parse(pool, pair)),
-                Rule::expr_list => expressions = Some(ExpressionList::parse(pool, pair)),
+                Rule::place => targets.push(Expression::parse(pool, pair, diagnostics)),
+                Rule::expr_list => {
+                    expressions = Some(ExpressionList::parse(pool, pair, diagnostics))
+                }
 
                 _ => unexpected_pair!(pair),
             }
@@ -0,0 +1,22 @@
+use pest::iterators::Pair;
+
+use rjc_ast::{statement::Break, AST, ASTRef, ASTType, Symbol};
+use rjc_diagnostics::Diagnostics;
+
+use crate::{ASTParser, Rule, SymbolFromPair};
+
+impl ASTParser for Break {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, _: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(pair.as_rule() == Rule::break_stmt);
+
+        let break_ = Break {
+            id: pool.len(),
+            symbol: Symbol::from_pair(&pair),
+        };
+
+        pool.add(break_)
+    }
+}
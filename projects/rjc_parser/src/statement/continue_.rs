@@ -0,0 +1,22 @@
+use pest::iterators::Pair;
+
+use rjc_ast::{statement::Continue, AST, ASTRef, ASTType, Symbol};
+use rjc_diagnostics::Diagnostics;
+
+use crate::{ASTParser, Rule, SymbolFromPair};
+
+impl ASTParser for Continue {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, _: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(pair.as_rule() == Rule::continue_stmt);
+
+        let continue_ = Continue {
+            id: pool.len(),
+            symbol: Symbol::from_pair(&pair),
+        };
+
+        pool.add(continue_)
+    }
+}
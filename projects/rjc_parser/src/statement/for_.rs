@@ -0,0 +1,62 @@
+use pest::iterators::Pair;
+
+use rjc_ast::{
+    expression::Expression, statement::For, ASTRef, ASTType, Block, Ident, Symbol, Type,
+    Variable, AST,
+};
+use rjc_diagnostics::Diagnostics;
+
+use crate::{ASTParser, Rule, SymbolFromPair};
+
+impl ASTParser for For {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(pair.as_rule() == Rule::for_stmt);
+
+        let mut variable = None;
+        let mut bounds = vec![];
+        let mut inclusive = false;
+        let mut block = None;
+
+        for pair in pair.into_inner() {
+            match pair.as_rule() {
+                Rule::ident => {
+                    // The loop variable is declared implicitly and counts in i32.
+                    let symbol = Symbol::from_pair(&pair);
+                    let name = Ident::parse(pool, pair, diagnostics);
+
+                    let variable_ = Variable {
+                        id: pool.len(),
+                        symbol,
+                        name,
+                        type_: Some(Type::I32),
+                        value: None,
+                    };
+
+                    variable = Some(pool.add(variable_));
+                }
+                Rule::expr_elm => bounds.push(Expression::parse(pool, pair, diagnostics)),
+                Rule::range_inclusive => inclusive = true,
+                Rule::range_exclusive => inclusive = false,
+                Rule::block => block = Some(Block::parse(pool, pair, diagnostics)),
+
+                _ => unexpected_pair!(pair),
+            }
+        }
+
+        let mut bounds = bounds.into_iter();
+
+        let for_ = For {
+            id: pool.len(),
+            variable: variable.expect("no variable in for statement"),
+            start: bounds.next().expect("no start in for statement"),
+            end: bounds.next().expect("no end in for statement"),
+            inclusive,
+            block: block.expect("no block in for statement"),
+        };
+
+        pool.add(for_)
+    }
+}
@@ -1,11 +1,16 @@
 use pest::iterators::Pair;
 
-use rjc_ast::{expression::Expression, statement::If, ASTRef, ASTType, Block, AST};
+use rjc_ast::{
+    expression::Expression,
+    statement::{ElseIf, If},
+    ASTRef, ASTType, Block, AST,
+};
+use rjc_diagnostics::Diagnostics;
 
 use crate::{ASTParser, Rule};
 
 impl ASTParser for If {
-    fn parse(pool: &mut AST, pair: Pair<Rule>) -> ASTRef<Self>
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType,
     {
@@ -13,11 +18,13 @@ impl ASTParser for If {
 
         let mut condition = None;
         let mut blocks = vec![];
+        let mut else_ifs = vec![];
 
         for pair in pair.into_inner() {
             match pair.as_rule() {
-                Rule::expr_elm => condition = Some(Expression::parse(pool, pair)),
-                Rule::block => blocks.push(Block::parse(pool, pair)),
+                Rule::expr_elm => condition = Some(Expression::parse(pool, pair, diagnostics)),
+                Rule::block => blocks.push(Block::parse(pool, pair, diagnostics)),
+                Rule::else_if => else_ifs.push(ElseIf::parse(pool, pair, diagnostics)),
 
                 _ => unexpected_pair!(pair),
             }
@@ -32,9 +39,39 @@ impl ASTParser for If {
             id: pool.len(),
             condition: condition.expect("no condition in if statement"),
             if_block,
+            else_ifs,
             else_block,
         };
 
         pool.add(if_)
     }
 }
+
+impl ASTParser for ElseIf {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(pair.as_rule() == Rule::else_if);
+
+        let mut condition = None;
+        let mut block = None;
+
+        for pair in pair.into_inner() {
+            match pair.as_rule() {
+                Rule::expr_elm => condition = Some(Expression::parse(pool, pair, diagnostics)),
+                Rule::block => block = Some(Block::parse(pool, pair, diagnostics)),
+
+                _ => unexpected_pair!(pair),
+            }
+        }
+
+        let else_if = ElseIf {
+            id: pool.len(),
+            condition: condition.expect("no condition in else if"),
+            block: block.expect("no block in else if"),
+        };
+
+        pool.add(else_if)
+    }
+}
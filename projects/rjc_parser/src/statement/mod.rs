@@ -1,5 +1,10 @@
 
+#[allow(clippy::module_inception)]
 mod statement;
 mod return_;
 mod assignment;
 mod if_;
+mod while_;
+mod for_;
+mod break_;
+mod continue_;
@@ -1,11 +1,12 @@
 use pest::iterators::Pair;
 
 use rjc_ast::{expression::ExpressionList, statement::Return, AST, ASTRef, ASTType, Symbol};
+use rjc_diagnostics::Diagnostics;
 
 use crate::{ASTParser, Rule, SymbolFromPair};
 
 impl ASTParser for Return {
-    fn parse(pool: &mut AST, pair: Pair<Rule>) -> ASTRef<Self>
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType,
     {
@@ -16,7 +17,9 @@ impl ASTParser for Return {
 
         for pair in pair.into_inner() {
             match pair.as_rule() {
-                Rule::expr_list => expression_list = Some(ExpressionList::parse(pool, pair)),
+                Rule::expr_list => {
+                    expression_list = Some(ExpressionList::parse(pool, pair, diagnostics))
+                }
 
                 _ => unexpected_pair!(pair),
             }
@@ -1,14 +1,15 @@
 use pest::iterators::Pair;
 
 use rjc_ast::{
-    statement::{Assignment, If, Return, Statement},
+    statement::{Assignment, Break, Continue, For, If, Return, Statement, While},
     Call, AST, ASTRef, ASTType, Variable,
 };
+use rjc_diagnostics::Diagnostics;
 
 use crate::{ASTParser, Rule};
 
 impl ASTParser for Statement {
-    fn parse(pool: &mut AST, pair: Pair<Rule>) -> ASTRef<Self>
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType,
     {
@@ -17,13 +18,29 @@ impl ASTParser for Statement {
         let pair = pair.into_inner().next().expect("no value in statement");
 
         let statement = match pair.as_rule() {
-            Rule::var_decl => {
-                Statement::VariableDeclaration((Variable::parse(pool, pair), pool.len()))
+            Rule::var_def => {
+                let variable = Variable::parse(pool, pair, diagnostics);
+
+                Statement::VariableDeclaration((variable, pool.len()))
+            }
+            Rule::func_ret => {
+                Statement::Return((Return::parse(pool, pair, diagnostics), pool.len()))
+            }
+            Rule::assign => {
+                Statement::Assignment((Assignment::parse(pool, pair, diagnostics), pool.len()))
+            }
+            Rule::if_stmt => Statement::If((If::parse(pool, pair, diagnostics), pool.len())),
+            Rule::func_call => Statement::Call((Call::parse(pool, pair, diagnostics), pool.len())),
+            Rule::while_stmt => {
+                Statement::While((While::parse(pool, pair, diagnostics), pool.len()))
+            }
+            Rule::for_stmt => Statement::For((For::parse(pool, pair, diagnostics), pool.len())),
+            Rule::break_stmt => {
+                Statement::Break((Break::parse(pool, pair, diagnostics), pool.len()))
+            }
+            Rule::continue_stmt => {
+                Statement::Continue((Continue::parse(pool, pair, diagnostics), pool.len()))
             }
-            Rule::func_ret => Statement::Return((Return::parse(pool, pair), pool.len())),
-            Rule::assign => Statement::Assignment((Assignment::parse(pool, pair), pool.len())),
-            Rule::if_stmt => Statement::If((If::parse(pool, pair), pool.len())),
-            Rule::func_call => Statement::Call((Call::parse(pool, pair), pool.len())),
 
             _ => unexpected_pair!(pair),
         };
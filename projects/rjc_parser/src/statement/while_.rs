@@ -0,0 +1,35 @@
+use pest::iterators::Pair;
+
+use rjc_ast::{expression::Expression, statement::While, ASTRef, ASTType, Block, AST};
+use rjc_diagnostics::Diagnostics;
+
+use crate::{ASTParser, Rule};
+
+impl ASTParser for While {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(pair.as_rule() == Rule::while_stmt);
+
+        let mut condition = None;
+        let mut block = None;
+
+        for pair in pair.into_inner() {
+            match pair.as_rule() {
+                Rule::expr_elm => condition = Some(Expression::parse(pool, pair, diagnostics)),
+                Rule::block => block = Some(Block::parse(pool, pair, diagnostics)),
+
+                _ => unexpected_pair!(pair),
+            }
+        }
+
+        let while_ = While {
+            id: pool.len(),
+            condition: condition.expect("no condition in while statement"),
+            block: block.expect("no block in while statement"),
+        };
+
+        pool.add(while_)
+    }
+}
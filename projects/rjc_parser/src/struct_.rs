@@ -0,0 +1,37 @@
+use pest::iterators::Pair;
+
+use rjc_ast::{Ident, Struct, AST, ASTRef, ASTType, Symbol, Variable};
+use rjc_diagnostics::Diagnostics;
+
+use crate::{ASTParser, Rule, SymbolFromPair};
+
+impl ASTParser for Struct {
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
+    where
+        Self: ASTType,
+    {
+        assert!(pair.as_rule() == Rule::struct_decl);
+
+        let symbol = Symbol::from_pair(&pair);
+        let mut name = None;
+        let mut fields = vec![];
+
+        for pair in pair.into_inner() {
+            match pair.as_rule() {
+                Rule::ident => name = Some(Ident::parse(pool, pair, diagnostics)),
+                Rule::var_decl => fields.push(Variable::parse(pool, pair, diagnostics)),
+
+                _ => unexpected_pair!(pair),
+            }
+        }
+
+        let struct_ = Struct {
+            id: pool.len(),
+            symbol,
+            ident: name.expect("no name defined for struct"),
+            fields,
+        };
+
+        pool.add(struct_)
+    }
+}
@@ -0,0 +1,47 @@
+use pest::iterators::Pair;
+use rjc_ast::{Symbol, Type};
+use rjc_diagnostics::{codes, Diagnostic, Diagnostics};
+
+use crate::{Rule, SymbolFromPair};
+
+/// Reads the type named by a `type_name` or `ret_type` pair. Names other than
+/// the builtin types refer to structs.
+pub fn parse_type(pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> Type {
+    let inner = pair.into_inner().next().expect("no type in type pair");
+
+    match inner.as_rule() {
+        Rule::type_name => parse_type(inner, diagnostics),
+        Rule::var_type => Type::from_str(inner.as_str()),
+        Rule::ident => Type::Struct(inner.as_str().to_string()),
+        Rule::array_type => {
+            let mut pairs = inner.into_inner();
+            let element = parse_type(pairs.next().expect("no element type"), diagnostics);
+            let length = array_length(pairs.next().expect("no array length"), diagnostics);
+
+            Type::Array(Box::new(element), length)
+        }
+
+        _ => unexpected_pair!(inner),
+    }
+}
+
+/// Reads the length of an array type or of an array repeating a value.
+pub fn array_length(pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> usize {
+    assert!(pair.as_rule() == Rule::array_length);
+
+    match pair.as_str().parse::<u32>() {
+        Ok(length) => length as usize,
+        Err(_) => {
+            let diagnostic = Diagnostic::error(
+                codes::LITERAL_OUT_OF_RANGE,
+                "array length is too large".to_string(),
+                &Symbol::from_pair(&pair),
+            )
+            .with_note(format!("arrays hold at most {} elements", u32::MAX));
+
+            diagnostics.push(diagnostic);
+
+            0
+        }
+    }
+}
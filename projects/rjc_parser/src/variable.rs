@@ -1,24 +1,27 @@
 use pest::iterators::Pair;
 
-use rjc_ast::{Ident, AST, ASTRef, ASTType, Symbol, Type, Variable};
+use rjc_ast::{expression::Expression, Ident, AST, ASTRef, ASTType, Symbol, Variable};
+use rjc_diagnostics::Diagnostics;
 
-use crate::{ASTParser, Rule, SymbolFromPair};
+use crate::{types::parse_type, ASTParser, Rule, SymbolFromPair};
 
 impl ASTParser for Variable {
-    fn parse(pool: &mut AST, pair: Pair<Rule>) -> ASTRef<Self>
+    fn parse(pool: &mut AST, pair: Pair<Rule>, diagnostics: &mut Diagnostics) -> ASTRef<Self>
     where
         Self: ASTType,
     {
-        assert!(pair.as_rule() == Rule::var_decl);
+        assert!(matches!(pair.as_rule(), Rule::var_decl | Rule::var_def));
 
         let symbol = Symbol::from_pair(&pair);
         let mut name = None;
         let mut _type = None;
+        let mut value = None;
 
         for pair in pair.into_inner() {
             match pair.as_rule() {
-                Rule::ident => name = Some(Ident::parse(pool, pair)),
-                Rule::var_type => _type = Some(Type::from_str(pair.as_str())),
+                Rule::ident => name = Some(Ident::parse(pool, pair, diagnostics)),
+                Rule::type_name => _type = Some(parse_type(pair, diagnostics)),
+                Rule::expr_elm => value = Some(Expression::parse(pool, pair, diagnostics)),
 
                 _ => unexpected_pair!(pair),
             }
@@ -28,7 +31,8 @@ impl ASTParser for Variable {
             id: pool.len(),
             symbol,
             name: name.expect("no name for variable"),
-            type_: _type.expect("no type for variable"),
+            type_: _type,
+            value,
         };
 
         pool.add(variable)
@@ -0,0 +1,15 @@
+use rjc_ast::{Block, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Block {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        context.push_scope();
+
+        for statement in &self.statements {
+            pool.get(*statement).resolve(pool, context);
+        }
+
+        context.pop_scope();
+    }
+}
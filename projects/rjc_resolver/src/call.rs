@@ -0,0 +1,11 @@
+use rjc_ast::{Call, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Call {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        context.resolve_function(pool, self.ident);
+
+        pool.get(self.expressions).resolve(pool, context);
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use rjc_ast::{ASTRef, Ident, Struct, Symbol, Type, AST};
+use rjc_diagnostics::{codes, Diagnostic, Diagnostics};
+
+use crate::{Declaration, Resolution};
+
+pub struct Context<'a> {
+    scopes: Vec<HashMap<String, Declaration>>,
+    loops: usize,
+    diagnostics: &'a mut Diagnostics,
+    pub resolution: Resolution,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(diagnostics: &'a mut Diagnostics) -> Context<'a> {
+        Context {
+            scopes: Vec::new(),
+            loops: 0,
+            diagnostics,
+            resolution: Resolution::default(),
+        }
+    }
+
+    pub fn push_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    pub fn pop_scope(&mut self) {
+        self.scopes.pop().expect("no scope to pop");
+    }
+
+    pub fn enter_loop(&mut self) {
+        self.loops += 1;
+    }
+
+    pub fn exit_loop(&mut self) {
+        self.loops -= 1;
+    }
+
+    /// Reports `keyword` (`break` or `continue`) if it is not inside a loop.
+    pub fn expect_loop(&mut self, keyword: &str, symbol: &Symbol) {
+        if self.loops > 0 {
+            return;
+        }
+
+        let diagnostic = Diagnostic::error(
+            codes::OUTSIDE_OF_LOOP,
+            format!("`{}` outside of a loop", keyword),
+            symbol,
+        )
+        .with_message(format!("cannot `{}` outside of a loop", keyword));
+
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// Adds a declaration to the innermost scope, reporting names that are
+    /// already declared in that same scope.
+    pub fn declare(&mut self, pool: &AST, declaration: Declaration) {
+        let ident = pool.get(declaration.ident(pool));
+
+        let scope = self.scopes.last_mut().expect("declaration outside of scope");
+
+        if let Some(previous) = scope.get(&ident.name) {
+            let previous = pool.get(previous.ident(pool));
+            let message = format!("`{}` is already declared in this scope", ident.name);
+
+            let diagnostic = Diagnostic::error(codes::DUPLICATE_DECLARATION, message, &ident.symbol)
+                .with_message("redeclared here".to_string())
+                .with_label(&previous.symbol, "previous declaration".to_string());
+
+            self.diagnostics.push(diagnostic);
+            return;
+        }
+
+        scope.insert(ident.name.clone(), declaration);
+    }
+
+    /// Looks up the declaration an identifier use refers to and records it
+    /// in the resolution.
+    pub fn resolve(&mut self, pool: &AST, ident: ASTRef<Ident>) -> Option<Declaration> {
+        let name = &pool.get(ident).name;
+
+        let declaration = self
+            .scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name))
+            .copied()?;
+
+        self.resolution.insert(ident, declaration);
+
+        Some(declaration)
+    }
+
+    /// Resolves an identifier that is used as a variable.
+    pub fn resolve_variable(&mut self, pool: &AST, ident: ASTRef<Ident>) {
+        let ident_node = pool.get(ident);
+
+        let diagnostic = match self.resolve(pool, ident) {
+            Some(Declaration::Variable(_)) => return,
+            Some(Declaration::Function(function)) => {
+                let function = pool.get(pool.get(function).ident);
+
+                Diagnostic::error(
+                    codes::NOT_A_VARIABLE,
+                    format!("`{}` is a function, not a variable", ident_node.name),
+                    &ident_node.symbol,
+                )
+                .with_label(&function.symbol, "function declared here".to_string())
+            }
+            None => Diagnostic::error(
+                codes::UNDECLARED_VARIABLE,
+                format!("cannot find variable `{}` in this scope", ident_node.name),
+                &ident_node.symbol,
+            )
+            .with_message("not found in this scope".to_string()),
+        };
+
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// Resolves an identifier that is called as a function.
+    pub fn resolve_function(&mut self, pool: &AST, ident: ASTRef<Ident>) {
+        let ident_node = pool.get(ident);
+
+        let diagnostic = match self.resolve(pool, ident) {
+            Some(Declaration::Function(_)) => return,
+            Some(Declaration::Variable(variable)) => {
+                let variable = pool.get(pool.get(variable).name);
+
+                Diagnostic::error(
+                    codes::NOT_A_FUNCTION,
+                    format!("`{}` is a variable, not a function", ident_node.name),
+                    &ident_node.symbol,
+                )
+                .with_label(&variable.symbol, "variable declared here".to_string())
+            }
+            None => Diagnostic::error(
+                codes::UNDECLARED_FUNCTION,
+                format!("cannot find function `{}`", ident_node.name),
+                &ident_node.symbol,
+            ),
+        };
+
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// Makes a struct known by its name, reporting names that are already
+    /// taken by another struct.
+    pub fn declare_struct(&mut self, pool: &AST, struct_: ASTRef<Struct>) {
+        let ident = pool.get(pool.get(struct_).ident);
+
+        if let Some(previous) = self.resolution.struct_(&ident.name) {
+            let previous = pool.get(pool.get(previous).ident);
+            let message = format!("struct `{}` is already declared", ident.name);
+
+            let diagnostic = Diagnostic::error(codes::DUPLICATE_DECLARATION, message, &ident.symbol)
+                .with_message("redeclared here".to_string())
+                .with_label(&previous.symbol, "previous declaration".to_string());
+
+            self.diagnostics.push(diagnostic);
+            return;
+        }
+
+        self.resolution.insert_struct(ident.name.clone(), struct_);
+    }
+
+    /// Reports struct types that are not declared.
+    pub fn resolve_type(&mut self, type_: &Type, symbol: &Symbol) {
+        let name = match type_ {
+            Type::Struct(name) => name,
+            Type::Array(element, _) => return self.resolve_type(element, symbol),
+
+            _ => return,
+        };
+
+        if self.resolution.struct_(name).is_none() {
+            let diagnostic = Diagnostic::error(
+                codes::UNDECLARED_TYPE,
+                format!("cannot find type `{}`", name),
+                symbol,
+            )
+            .with_message("not found in this module".to_string());
+
+            self.diagnostics.push(diagnostic);
+        }
+    }
+
+    pub fn diagnostic(&mut self, diagnostic: Diagnostic) {
+        self.diagnostics.push(diagnostic);
+    }
+}
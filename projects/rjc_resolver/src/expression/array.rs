@@ -0,0 +1,20 @@
+use rjc_ast::{
+    expression::{ArrayLiteral, ArrayRepeat},
+    AST,
+};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for ArrayLiteral {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        for element in &self.elements {
+            pool.get(*element).resolve(pool, context);
+        }
+    }
+}
+
+impl Resolve for ArrayRepeat {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.value).resolve(pool, context);
+    }
+}
@@ -0,0 +1,13 @@
+use rjc_ast::{expression::Bitwise, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Bitwise {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.first).resolve(pool, context);
+
+        for op in &self.rest {
+            pool.get(op.expression()).resolve(pool, context);
+        }
+    }
+}
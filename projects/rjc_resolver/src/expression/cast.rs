@@ -0,0 +1,9 @@
+use rjc_ast::{expression::Cast, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Cast {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.expression).resolve(pool, context);
+    }
+}
@@ -0,0 +1,10 @@
+use rjc_ast::{expression::Cmp, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Cmp {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.left).resolve(pool, context);
+        pool.get(self.right).resolve(pool, context);
+    }
+}
@@ -0,0 +1,25 @@
+use rjc_ast::{expression::Expression, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Expression {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        match self {
+            Expression::Literal(_) => (),
+            Expression::Ident((node, _)) => context.resolve_variable(pool, *node),
+            Expression::Call((node, _)) => pool.get(*node).resolve(pool, context),
+            Expression::Cmp((node, _)) => pool.get(*node).resolve(pool, context),
+            Expression::Sum((node, _)) => pool.get(*node).resolve(pool, context),
+            Expression::Product((node, _)) => pool.get(*node).resolve(pool, context),
+            Expression::Logical((node, _)) => pool.get(*node).resolve(pool, context),
+            Expression::Unary((node, _)) => pool.get(*node).resolve(pool, context),
+            Expression::Bitwise((node, _)) => pool.get(*node).resolve(pool, context),
+            Expression::Cast((node, _)) => pool.get(*node).resolve(pool, context),
+            Expression::Field((node, _)) => pool.get(*node).resolve(pool, context),
+            Expression::StructLiteral((node, _)) => pool.get(*node).resolve(pool, context),
+            Expression::Index((node, _)) => pool.get(*node).resolve(pool, context),
+            Expression::ArrayLiteral((node, _)) => pool.get(*node).resolve(pool, context),
+            Expression::ArrayRepeat((node, _)) => pool.get(*node).resolve(pool, context),
+        }
+    }
+}
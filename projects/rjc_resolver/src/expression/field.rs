@@ -0,0 +1,11 @@
+use rjc_ast::{expression::Field, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Field {
+    /// Only the accessed struct is resolved, the field name depends on its
+    /// type and is looked up by the typer.
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.expression).resolve(pool, context);
+    }
+}
@@ -0,0 +1,10 @@
+use rjc_ast::{expression::Index, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Index {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.expression).resolve(pool, context);
+        pool.get(self.index).resolve(pool, context);
+    }
+}
@@ -0,0 +1,11 @@
+use rjc_ast::{expression::ExpressionList, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for ExpressionList {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        for expression in &self.list {
+            pool.get(*expression).resolve(pool, context);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use rjc_ast::{
+    expression::{Logical, LogicalOp},
+    AST,
+};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Logical {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.first).resolve(pool, context);
+
+        for op in &self.rest {
+            match op {
+                LogicalOp::And(expression) | LogicalOp::Or(expression) => {
+                    pool.get(*expression).resolve(pool, context)
+                }
+            }
+        }
+    }
+}
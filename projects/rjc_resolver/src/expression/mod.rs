@@ -0,0 +1,14 @@
+mod array;
+mod bitwise;
+mod cast;
+mod cmp;
+#[allow(clippy::module_inception)]
+mod expression;
+mod field;
+mod index;
+mod list;
+mod logical;
+mod product;
+mod struct_literal;
+mod sum;
+mod unary;
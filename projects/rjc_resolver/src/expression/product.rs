@@ -0,0 +1,20 @@
+use rjc_ast::{
+    expression::{Product, ProductOp},
+    AST,
+};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Product {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.first).resolve(pool, context);
+
+        for op in &self.rest {
+            match op {
+                ProductOp::Mul(expression)
+                | ProductOp::Div(expression)
+                | ProductOp::Rem(expression) => pool.get(*expression).resolve(pool, context),
+            }
+        }
+    }
+}
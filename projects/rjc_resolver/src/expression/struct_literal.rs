@@ -0,0 +1,15 @@
+use rjc_ast::{expression::StructLiteral, Type, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for StructLiteral {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        let ident = pool.get(self.ident);
+
+        context.resolve_type(&Type::Struct(ident.name.clone()), &ident.symbol);
+
+        for field in &self.fields {
+            pool.get(pool.get(*field).expression).resolve(pool, context);
+        }
+    }
+}
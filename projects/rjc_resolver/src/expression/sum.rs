@@ -0,0 +1,20 @@
+use rjc_ast::{
+    expression::{Sum, SumOp},
+    AST,
+};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Sum {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.first).resolve(pool, context);
+
+        for op in &self.rest {
+            match op {
+                SumOp::Add(expression) | SumOp::Sub(expression) => {
+                    pool.get(*expression).resolve(pool, context)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use rjc_ast::{expression::Unary, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Unary {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.expression).resolve(pool, context);
+    }
+}
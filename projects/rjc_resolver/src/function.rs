@@ -0,0 +1,26 @@
+use rjc_ast::{Function, AST};
+
+use crate::{context::Context, Declaration, Resolve};
+
+impl Resolve for Function {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        context.push_scope();
+
+        for type_ in &self.return_type.list {
+            context.resolve_type(type_, &self.symbol);
+        }
+
+        for arg in &self.args {
+            pool.get(*arg).resolve(pool, context);
+            context.declare(pool, Declaration::Variable(*arg));
+        }
+
+        // The function body shares the scope of the arguments, so a local
+        // may not redeclare an argument.
+        for statement in &pool.get(self.block).statements {
+            pool.get(*statement).resolve(pool, context);
+        }
+
+        context.pop_scope();
+    }
+}
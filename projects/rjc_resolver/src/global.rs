@@ -0,0 +1,12 @@
+use rjc_ast::{Global, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Global {
+    /// The value may use any global, whether it is known at compile time is
+    /// checked by the typer.
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.variable).resolve(pool, context);
+        pool.get(self.value).resolve(pool, context);
+    }
+}
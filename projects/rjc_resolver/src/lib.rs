@@ -0,0 +1,33 @@
+use rjc_ast::AST;
+use rjc_diagnostics::Diagnostics;
+
+mod block;
+mod call;
+mod context;
+mod expression;
+mod function;
+mod global;
+mod module;
+mod resolution;
+mod statement;
+mod struct_;
+mod variable;
+
+use context::Context;
+
+pub use resolution::{Declaration, Resolution};
+
+trait Resolve {
+    fn resolve(&self, pool: &AST, context: &mut Context);
+}
+
+/// Binds every identifier use in the AST to the variable or function it refers to.
+/// Identifiers that cannot be resolved are reported to `diagnostics` and left
+/// out of the resolution.
+pub fn resolve(pool: &AST, diagnostics: &mut Diagnostics) -> Resolution {
+    let mut context = Context::new(diagnostics);
+
+    pool.get(pool.module()).resolve(pool, &mut context);
+
+    context.resolution
+}
@@ -0,0 +1,65 @@
+use rjc_ast::{Module, Symbol, AST};
+use rjc_diagnostics::{codes, Diagnostic};
+
+use crate::{context::Context, Declaration, Resolve};
+
+impl Resolve for Module {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        context.push_scope();
+
+        for struct_ in &self.structs {
+            context.declare_struct(pool, *struct_);
+        }
+
+        for struct_ in &self.structs {
+            pool.get(*struct_).resolve(pool, context);
+        }
+
+        // Globals and functions are declared up front so they can be used
+        // before their definition.
+        for global in &self.globals {
+            let variable = pool.get(*global).variable;
+
+            context.declare(pool, Declaration::Variable(variable));
+            context.resolution.insert_global(variable, *global);
+        }
+
+        for function in &self.functions {
+            context.declare(pool, Declaration::Function(*function));
+        }
+
+        let main = self
+            .functions
+            .iter()
+            .any(|function| pool.get(pool.get(*function).ident).name == "main");
+
+        if !main {
+            // The start of the file, as there is no declaration to point at.
+            let symbol = Symbol {
+                line: 1,
+                column: 1,
+                start: 0,
+                end: 0,
+            };
+
+            let diagnostic = Diagnostic::error(
+                codes::MISSING_MAIN,
+                "`main` function not found".to_string(),
+                &symbol,
+            )
+            .with_note("the program starts at `fn main(): i32`".to_string());
+
+            context.diagnostic(diagnostic);
+        }
+
+        for global in &self.globals {
+            pool.get(*global).resolve(pool, context);
+        }
+
+        for function in &self.functions {
+            pool.get(*function).resolve(pool, context);
+        }
+
+        context.pop_scope();
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use rjc_ast::{ASTRef, Function, Global, Ident, Struct, Variable, AST};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Declaration {
+    Variable(ASTRef<Variable>),
+    Function(ASTRef<Function>),
+}
+
+impl Declaration {
+    /// The identifier naming the declaration.
+    pub fn ident(&self, pool: &AST) -> ASTRef<Ident> {
+        match self {
+            Declaration::Variable(variable) => pool.get(*variable).name,
+            Declaration::Function(function) => pool.get(*function).ident,
+        }
+    }
+}
+
+/// Side table from identifier uses to their declarations, from struct names
+/// to their declarations and from the variables of globals to the globals.
+#[derive(Debug, Default)]
+pub struct Resolution {
+    declarations: HashMap<ASTRef<Ident>, Declaration>,
+    structs: HashMap<String, ASTRef<Struct>>,
+    globals: HashMap<ASTRef<Variable>, ASTRef<Global>>,
+}
+
+impl Resolution {
+    pub fn insert(&mut self, ident: ASTRef<Ident>, declaration: Declaration) {
+        self.declarations.insert(ident, declaration);
+    }
+
+    pub fn declaration(&self, ident: ASTRef<Ident>) -> Option<Declaration> {
+        self.declarations.get(&ident).copied()
+    }
+
+    pub fn variable(&self, ident: ASTRef<Ident>) -> Option<ASTRef<Variable>> {
+        match self.declaration(ident)? {
+            Declaration::Variable(variable) => Some(variable),
+
+            _ => None,
+        }
+    }
+
+    pub fn function(&self, ident: ASTRef<Ident>) -> Option<ASTRef<Function>> {
+        match self.declaration(ident)? {
+            Declaration::Function(function) => Some(function),
+
+            _ => None,
+        }
+    }
+
+    pub fn insert_struct(&mut self, name: String, struct_: ASTRef<Struct>) {
+        self.structs.insert(name, struct_);
+    }
+
+    pub fn struct_(&self, name: &str) -> Option<ASTRef<Struct>> {
+        self.structs.get(name).copied()
+    }
+
+    pub fn insert_global(&mut self, variable: ASTRef<Variable>, global: ASTRef<Global>) {
+        self.globals.insert(variable, global);
+    }
+
+    /// The global a variable belongs to, `None` for locals and arguments.
+    pub fn global(&self, variable: ASTRef<Variable>) -> Option<ASTRef<Global>> {
+        self.globals.get(&variable).copied()
+    }
+}
@@ -0,0 +1,49 @@
+use rjc_ast::{expression::Expression, statement::Assignment, ASTRef, Ident, AST};
+use rjc_diagnostics::{codes, Diagnostic};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Assignment {
+    /// Constants, including their fields and elements, cannot be assigned to.
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.expressions).resolve(pool, context);
+
+        for target in &self.targets {
+            pool.get(*target).resolve(pool, context);
+
+            let ident = root(pool, *target);
+            let global = context
+                .resolution
+                .variable(ident)
+                .and_then(|variable| context.resolution.global(variable))
+                .map(|global| pool.get(global));
+
+            if let Some(global) = global.filter(|global| global.constant) {
+                let ident = pool.get(ident);
+                let declaration = pool.get(pool.get(global.variable).name);
+
+                let diagnostic = Diagnostic::error(
+                    codes::ASSIGNMENT_TO_CONSTANT,
+                    format!("cannot assign to constant `{}`", ident.name),
+                    pool.get(*target).symbol(pool),
+                )
+                .with_label(&declaration.symbol, "constant declared here".to_string());
+
+                context.diagnostic(diagnostic);
+            }
+        }
+    }
+}
+
+/// The variable an assignment target is part of.
+fn root(pool: &AST, target: ASTRef<Expression>) -> ASTRef<Ident> {
+    match pool.get(target) {
+        Expression::Ident((ident, _)) => *ident,
+        Expression::Field((node, _)) => root(pool, pool.get(*node).expression),
+        Expression::Index((node, _)) => root(pool, pool.get(*node).expression),
+
+        // Targets are parsed from the `place` rule, an ident followed by
+        // field and index suffixes.
+        _ => unreachable!("invalid assignment target"),
+    }
+}
@@ -0,0 +1,20 @@
+use rjc_ast::{statement::For, AST};
+
+use crate::{context::Context, Declaration, Resolve};
+
+impl Resolve for For {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.start).resolve(pool, context);
+        pool.get(self.end).resolve(pool, context);
+
+        // The loop variable lives in a scope of its own around the loop block.
+        context.push_scope();
+        context.declare(pool, Declaration::Variable(self.variable));
+
+        context.enter_loop();
+        pool.get(self.block).resolve(pool, context);
+        context.exit_loop();
+
+        context.pop_scope();
+    }
+}
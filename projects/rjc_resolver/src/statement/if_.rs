@@ -0,0 +1,28 @@
+use rjc_ast::{
+    statement::{ElseIf, If},
+    AST,
+};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for If {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.condition).resolve(pool, context);
+        pool.get(self.if_block).resolve(pool, context);
+
+        for else_if in &self.else_ifs {
+            pool.get(*else_if).resolve(pool, context);
+        }
+
+        if let Some(else_block) = self.else_block {
+            pool.get(else_block).resolve(pool, context);
+        }
+    }
+}
+
+impl Resolve for ElseIf {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.condition).resolve(pool, context);
+        pool.get(self.block).resolve(pool, context);
+    }
+}
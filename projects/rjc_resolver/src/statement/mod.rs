@@ -0,0 +1,7 @@
+mod assignment;
+mod for_;
+mod if_;
+mod return_;
+mod while_;
+#[allow(clippy::module_inception)]
+mod statement;
@@ -0,0 +1,9 @@
+use rjc_ast::{statement::Return, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Return {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.expressions).resolve(pool, context);
+    }
+}
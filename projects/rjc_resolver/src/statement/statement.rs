@@ -0,0 +1,24 @@
+use rjc_ast::{statement::Statement, AST};
+
+use crate::{context::Context, Declaration, Resolve};
+
+impl Resolve for Statement {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        match self {
+            Statement::VariableDeclaration((node, _)) => {
+                pool.get(*node).resolve(pool, context);
+                context.declare(pool, Declaration::Variable(*node))
+            }
+            Statement::Call((node, _)) => pool.get(*node).resolve(pool, context),
+            Statement::Assignment((node, _)) => pool.get(*node).resolve(pool, context),
+            Statement::If((node, _)) => pool.get(*node).resolve(pool, context),
+            Statement::Return((node, _)) => pool.get(*node).resolve(pool, context),
+            Statement::While((node, _)) => pool.get(*node).resolve(pool, context),
+            Statement::For((node, _)) => pool.get(*node).resolve(pool, context),
+            Statement::Break((node, _)) => context.expect_loop("break", &pool.get(*node).symbol),
+            Statement::Continue((node, _)) => {
+                context.expect_loop("continue", &pool.get(*node).symbol)
+            }
+        }
+    }
+}
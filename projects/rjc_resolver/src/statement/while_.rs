@@ -0,0 +1,13 @@
+use rjc_ast::{statement::While, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for While {
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        pool.get(self.condition).resolve(pool, context);
+
+        context.enter_loop();
+        pool.get(self.block).resolve(pool, context);
+        context.exit_loop();
+    }
+}
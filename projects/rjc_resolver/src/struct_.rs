@@ -0,0 +1,73 @@
+use std::collections::HashSet;
+
+use rjc_ast::{Struct, Type, AST};
+use rjc_diagnostics::{codes, Diagnostic};
+
+use crate::{context::Context, Declaration, Resolve, Resolution};
+
+impl Resolve for Struct {
+    /// Checks the field types and names. A struct may not contain itself, as
+    /// it would be infinitely large.
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        context.push_scope();
+
+        for field in &self.fields {
+            pool.get(*field).resolve(pool, context);
+            context.declare(pool, Declaration::Variable(*field));
+        }
+
+        context.pop_scope();
+
+        let ident = pool.get(self.ident);
+
+        if contains(pool, &context.resolution, self, &ident.name, &mut HashSet::new()) {
+            let diagnostic = Diagnostic::error(
+                codes::RECURSIVE_TYPE,
+                format!("recursive type `{}` has infinite size", ident.name),
+                &ident.symbol,
+            );
+
+            context.diagnostic(diagnostic);
+        }
+    }
+}
+
+/// Whether `struct_` holds a value of the struct `name`, directly, in an
+/// array or in the fields of a struct it holds.
+fn contains<'a>(
+    pool: &'a AST,
+    resolution: &Resolution,
+    struct_: &'a Struct,
+    name: &'a str,
+    visited: &mut HashSet<&'a str>,
+) -> bool {
+    struct_.fields.iter().any(|field| {
+        // Fields always declare their type.
+        let mut type_ = match &pool.get(*field).type_ {
+            Some(type_) => type_,
+            None => return false,
+        };
+
+        while let Type::Array(element, _) = type_ {
+            type_ = element;
+        }
+
+        let field_struct = match type_ {
+            Type::Struct(field_struct) => field_struct.as_str(),
+
+            _ => return false,
+        };
+
+        if field_struct == name {
+            return true;
+        }
+
+        match resolution.struct_(field_struct) {
+            Some(inner) if visited.insert(field_struct) => {
+                contains(pool, resolution, pool.get(inner), name, visited)
+            }
+
+            _ => false,
+        }
+    })
+}
@@ -0,0 +1,18 @@
+use rjc_ast::{Variable, AST};
+
+use crate::{context::Context, Resolve};
+
+impl Resolve for Variable {
+    /// Checks that the type of the variable exists and resolves its initial
+    /// value. Declaring it is left to the caller, after the value, so that the
+    /// value cannot refer to the variable itself.
+    fn resolve(&self, pool: &AST, context: &mut Context) {
+        if let Some(type_) = &self.type_ {
+            context.resolve_type(type_, &self.symbol);
+        }
+
+        if let Some(value) = self.value {
+            pool.get(value).resolve(pool, context);
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use rjc_ast::{Block, AST};
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for Block {
+    type Output = ();
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        for statement in &self.statements {
+            pool.get(*statement).check(pool, context);
+        }
+    }
+}
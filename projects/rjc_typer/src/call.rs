@@ -0,0 +1,48 @@
+use rjc_ast::{Call, Type, AST};
+use rjc_diagnostics::codes;
+
+use crate::{context::Context, expression::list::check_list, TypeCheck};
+
+impl TypeCheck for Call {
+    type Output = Option<Vec<Type>>;
+
+    /// Checks the arguments against the parameters of the called function
+    /// and returns its return types, or `None` if the function is unknown.
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let function = context.resolution.function(self.ident).map(|f| pool.get(f));
+
+        let parameters: Vec<_> = match function {
+            Some(function) => function
+                .args
+                .iter()
+                .map(|arg| context.types.variable(*arg).clone())
+                .collect(),
+            None => vec![],
+        };
+
+        let arguments = check_list(pool.get(self.expressions), pool, context, &parameters);
+        let function = function?;
+        let name = &pool.get(function.ident).name;
+
+        match arguments {
+            Some(arguments) if arguments.len() != function.args.len() => {
+                let message = format!(
+                    "function `{}` takes {} argument(s) but {} were supplied",
+                    name,
+                    function.args.len(),
+                    arguments.len()
+                );
+
+                context.error(codes::ARGUMENT_COUNT, &self.symbol, message);
+            }
+            Some(arguments) => {
+                for ((type_, symbol), parameter) in arguments.iter().zip(&parameters) {
+                    context.expect(symbol, parameter, type_);
+                }
+            }
+            None => (),
+        }
+
+        Some(function.return_type.list.clone())
+    }
+}
@@ -0,0 +1,451 @@
+use std::cmp::Ordering;
+
+use rjc_ast::{
+    expression::{
+        Bitwise, BitwiseOp, Cast, Cmp, CmpOp, Expression, Literal, LiteralValue, Logical,
+        LogicalOp, Product, ProductOp, Sum, SumOp, Unary, UnaryOp,
+    },
+    ASTRef, Global, Ident, Symbol, Type, AST,
+};
+use rjc_diagnostics::{codes, Diagnostic};
+
+use crate::context::Context;
+
+/// A value computed at compile time. Integers hold their value rather than
+/// their bits, so negative numbers are negative whatever their type.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum Constant {
+    Integer(i128),
+    Float(f64),
+    Bool(bool),
+    Char(char),
+    Str(String),
+    Array(Vec<Constant>),
+    /// Field values in declaration order.
+    Struct(Vec<Constant>),
+}
+
+/// The value of a global. Values that are not known at compile time are
+/// reported, as are constants depending on themselves. `None` after an
+/// error.
+pub fn global(pool: &AST, context: &mut Context, global: ASTRef<Global>) -> Option<Constant> {
+    if let Some(value) = context.constants.get(&global) {
+        return value.clone();
+    }
+
+    let node = pool.get(global);
+
+    // A mismatch with the declared type is reported by the typer.
+    if context.types.expression(node.value) != context.types.variable(node.variable) {
+        context.constants.insert(global, None);
+
+        return None;
+    }
+
+    context.evaluating.push(global);
+    let value = evaluate(pool, context, node.value);
+    context.evaluating.pop();
+
+    context.constants.insert(global, value.clone());
+
+    value
+}
+
+/// Computes an expression the way the generated code would at runtime,
+/// including the wrapping of integers and the rounding of `f32`. Unknown
+/// types and operands of the wrong type stem from errors reported by the
+/// typer and give `None` as well.
+fn evaluate(pool: &AST, context: &mut Context, expression: ASTRef<Expression>) -> Option<Constant> {
+    let type_ = context.types.expression(expression).clone();
+
+    if type_ == Type::Unknown {
+        return None;
+    }
+
+    match pool.get(expression) {
+        Expression::Literal((node, _)) => Some(literal(pool.get(*node), &type_)),
+        Expression::Ident((node, _)) => ident(pool, context, *node),
+        Expression::Call((node, _)) => {
+            let message = "function calls are not allowed in constant values".to_string();
+
+            context.error(codes::NOT_CONSTANT, &pool.get(*node).symbol, message);
+
+            None
+        }
+        Expression::Cmp((node, _)) => cmp(pool, context, pool.get(*node)),
+        Expression::Sum((node, _)) => sum(pool, context, pool.get(*node), &type_),
+        Expression::Product((node, _)) => product(pool, context, pool.get(*node), &type_),
+        Expression::Logical((node, _)) => logical(pool, context, pool.get(*node)),
+        Expression::Unary((node, _)) => unary(pool, context, pool.get(*node), &type_),
+        Expression::Bitwise((node, _)) => bitwise(pool, context, pool.get(*node), &type_),
+        Expression::Cast((node, _)) => cast(pool, context, pool.get(*node)),
+        Expression::Field((node, _)) => {
+            let field = pool.get(*node);
+            let struct_ = context.types.expression(field.expression).clone();
+            let index = field_index(pool, context, &struct_, &pool.get(field.field).name)?;
+
+            match evaluate(pool, context, field.expression)? {
+                Constant::Struct(mut values) => Some(values.swap_remove(index)),
+
+                _ => None,
+            }
+        }
+        Expression::StructLiteral((node, _)) => {
+            let literal = pool.get(*node);
+            let mut values = vec![None; literal.fields.len()];
+
+            for field in &literal.fields {
+                let field = pool.get(*field);
+                let name = &pool.get(field.ident).name;
+                let index = field_index(pool, context, &type_, name)?;
+
+                values[index] = Some(evaluate(pool, context, field.expression)?);
+            }
+
+            // Missing fields are reported by the typer.
+            values.into_iter().collect::<Option<_>>().map(Constant::Struct)
+        }
+        Expression::Index((node, _)) => {
+            let index = pool.get(*node);
+            let array = evaluate(pool, context, index.expression)?;
+            let offset = evaluate(pool, context, index.index)?;
+
+            let (mut values, offset) = match (array, offset) {
+                (Constant::Array(values), Constant::Integer(offset)) => (values, offset),
+
+                _ => return None,
+            };
+
+            if 0 <= offset && offset < values.len() as i128 {
+                return Some(values.swap_remove(offset as usize));
+            }
+
+            // Literal indices are checked by the typer.
+            if !matches!(pool.get(index.index), Expression::Literal(_)) {
+                let diagnostic = Diagnostic::error(
+                    codes::INDEX_OUT_OF_BOUNDS,
+                    "index out of bounds".to_string(),
+                    pool.get(index.index).symbol(pool),
+                )
+                .with_message(format!(
+                    "the length is {} but the index is {}",
+                    values.len(),
+                    offset
+                ));
+
+                context.diagnostic(diagnostic);
+            }
+
+            None
+        }
+        Expression::ArrayLiteral((node, _)) => pool
+            .get(*node)
+            .elements
+            .iter()
+            .map(|element| evaluate(pool, context, *element))
+            .collect::<Option<_>>()
+            .map(Constant::Array),
+        Expression::ArrayRepeat((node, _)) => {
+            let repeat = pool.get(*node);
+            let value = evaluate(pool, context, repeat.value)?;
+
+            Some(Constant::Array(vec![value; repeat.length]))
+        }
+    }
+}
+
+fn literal(literal: &Literal, type_: &Type) -> Constant {
+    match &literal.value {
+        LiteralValue::Integer { value, .. } => Constant::Integer(*value),
+        LiteralValue::Float { value, .. } => Constant::Float(round(*value, type_)),
+        LiteralValue::Bool(value) => Constant::Bool(*value),
+        LiteralValue::Char(value) => Constant::Char(*value),
+        LiteralValue::Str(value) => Constant::Str(value.clone()),
+    }
+}
+
+/// Reads another constant. A `var` may change and is not known at compile
+/// time.
+fn ident(pool: &AST, context: &mut Context, ident: ASTRef<Ident>) -> Option<Constant> {
+    // Unresolved identifiers are reported by the resolver, and only globals
+    // are in scope outside of functions.
+    let variable = context.resolution.variable(ident)?;
+    let global = context.resolution.global(variable)?;
+
+    let ident = pool.get(ident);
+    let declaration = &pool.get(pool.get(variable).name).symbol;
+
+    if !pool.get(global).constant {
+        let message = format!("cannot read `var` `{}` in a constant value", ident.name);
+
+        not_constant(context, &ident.symbol, message, declaration);
+
+        return None;
+    }
+
+    if context.evaluating.contains(&global) {
+        let diagnostic = Diagnostic::error(
+            codes::CYCLIC_CONSTANT,
+            format!("constant `{}` depends on its own value", ident.name),
+            &ident.symbol,
+        )
+        .with_label(declaration, "constant declared here".to_string());
+
+        context.diagnostic(diagnostic);
+
+        return None;
+    }
+
+    self::global(pool, context, global)
+}
+
+fn not_constant(context: &mut Context, symbol: &Symbol, message: String, declaration: &Symbol) {
+    let diagnostic = Diagnostic::error(codes::NOT_CONSTANT, message, symbol)
+        .with_label(declaration, "declared here".to_string());
+
+    context.diagnostic(diagnostic);
+}
+
+fn cmp(pool: &AST, context: &mut Context, cmp: &Cmp) -> Option<Constant> {
+    let left = evaluate(pool, context, cmp.left)?;
+    let right = evaluate(pool, context, cmp.right)?;
+
+    // NaN is unordered, which makes every comparison but `!=` false.
+    let ordering = left.partial_cmp(&right);
+
+    let value = match cmp.op {
+        CmpOp::Eq => ordering == Some(Ordering::Equal),
+        CmpOp::Ne => ordering != Some(Ordering::Equal),
+        CmpOp::Le => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+        CmpOp::Ge => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+        CmpOp::Lt => ordering == Some(Ordering::Less),
+        CmpOp::Gt => ordering == Some(Ordering::Greater),
+    };
+
+    Some(Constant::Bool(value))
+}
+
+fn sum(pool: &AST, context: &mut Context, sum: &Sum, type_: &Type) -> Option<Constant> {
+    let mut value = evaluate(pool, context, sum.first)?;
+
+    for op in &sum.rest {
+        value = match op {
+            SumOp::Add(right) => {
+                let right = evaluate(pool, context, *right)?;
+
+                arithmetic(value, right, type_, i128::wrapping_add, |a, b| a + b)?
+            }
+            SumOp::Sub(right) => {
+                let right = evaluate(pool, context, *right)?;
+
+                arithmetic(value, right, type_, i128::wrapping_sub, |a, b| a - b)?
+            }
+        };
+    }
+
+    Some(value)
+}
+
+fn product(
+    pool: &AST,
+    context: &mut Context,
+    product: &Product,
+    type_: &Type,
+) -> Option<Constant> {
+    let mut value = evaluate(pool, context, product.first)?;
+
+    for op in &product.rest {
+        let (divisor, right) = match op {
+            ProductOp::Mul(right) => {
+                let right = evaluate(pool, context, *right)?;
+
+                value = arithmetic(value, right, type_, i128::wrapping_mul, |a, b| a * b)?;
+                continue;
+            }
+            ProductOp::Div(divisor) | ProductOp::Rem(divisor) => {
+                (*divisor, evaluate(pool, context, *divisor)?)
+            }
+        };
+
+        if right == Constant::Integer(0) {
+            // Dividing by a literal `0` is reported by the typer.
+            if !matches!(pool.get(divisor), Expression::Literal(_)) {
+                context.error(
+                    codes::DIVISION_BY_ZERO,
+                    pool.get(divisor).symbol(pool),
+                    "attempt to divide by zero in a constant value".to_string(),
+                );
+            }
+
+            return None;
+        }
+
+        value = match op {
+            ProductOp::Div(_) => arithmetic(value, right, type_, i128::wrapping_div, |a, b| a / b),
+            _ => arithmetic(value, right, type_, i128::wrapping_rem, |a, b| a % b),
+        }?;
+    }
+
+    Some(value)
+}
+
+fn logical(pool: &AST, context: &mut Context, logical: &Logical) -> Option<Constant> {
+    let mut value = evaluate(pool, context, logical.first)?;
+
+    // Operands after the one deciding the result are not evaluated, like
+    // at runtime.
+    for op in &logical.rest {
+        value = match (op, value) {
+            (LogicalOp::And(right), Constant::Bool(true))
+            | (LogicalOp::Or(right), Constant::Bool(false)) => evaluate(pool, context, *right)?,
+            (_, value) => value,
+        };
+    }
+
+    Some(value)
+}
+
+fn unary(pool: &AST, context: &mut Context, unary: &Unary, type_: &Type) -> Option<Constant> {
+    let value = evaluate(pool, context, unary.expression)?;
+
+    match (&unary.op, value) {
+        (UnaryOp::Not, Constant::Bool(value)) => Some(Constant::Bool(!value)),
+        (UnaryOp::Neg, Constant::Integer(value)) => wrap(-value, type_).map(Constant::Integer),
+        (UnaryOp::Neg, Constant::Float(value)) => Some(Constant::Float(-value)),
+        (UnaryOp::BitNot, Constant::Integer(value)) => wrap(!value, type_).map(Constant::Integer),
+
+        _ => None,
+    }
+}
+
+fn bitwise(pool: &AST, context: &mut Context, bitwise: &Bitwise, type_: &Type) -> Option<Constant> {
+    let bits = type_.integer_bits()?;
+    let mut value = integer(evaluate(pool, context, bitwise.first)?)?;
+
+    for op in &bitwise.rest {
+        let right = integer(evaluate(pool, context, op.expression())?)?;
+
+        // Shift amounts wrap around at the bit width like at runtime.
+        let amount = (right & (bits as i128 - 1)) as u32;
+
+        value = wrap(
+            match op {
+                BitwiseOp::Or(_) => value | right,
+                BitwiseOp::Xor(_) => value ^ right,
+                BitwiseOp::And(_) => value & right,
+                BitwiseOp::Shl(_) => value << amount,
+                // Signed values are negative if their sign bit is set, so
+                // the shift is arithmetic for them and logical otherwise.
+                BitwiseOp::Shr(_) => value >> amount,
+            },
+            type_,
+        )?;
+    }
+
+    Some(Constant::Integer(value))
+}
+
+/// Conversions as done by `as` at runtime.
+fn cast(pool: &AST, context: &mut Context, cast: &Cast) -> Option<Constant> {
+    let value = evaluate(pool, context, cast.expression)?;
+    let to = &cast.type_;
+
+    match value {
+        Constant::Integer(value) if *to == Type::Char => Some(Constant::Char(character(value))),
+        Constant::Integer(value) if *to == Type::F32 => Some(Constant::Float(value as f32 as f64)),
+        Constant::Integer(value) if *to == Type::F64 => Some(Constant::Float(value as f64)),
+        Constant::Integer(value) => wrap(value, to).map(Constant::Integer),
+        Constant::Bool(value) => wrap(value as i128, to).map(Constant::Integer),
+        Constant::Char(value) if *to == Type::Char => Some(Constant::Char(value)),
+        Constant::Char(value) => wrap(value as i128, to).map(Constant::Integer),
+        Constant::Float(value) if to.is_float() => Some(Constant::Float(round(value, to))),
+        Constant::Float(value) => saturate(value, to).map(Constant::Integer),
+
+        _ => None,
+    }
+}
+
+fn arithmetic(
+    left: Constant,
+    right: Constant,
+    type_: &Type,
+    integer: fn(i128, i128) -> i128,
+    float: fn(f64, f64) -> f64,
+) -> Option<Constant> {
+    match (left, right) {
+        (Constant::Integer(left), Constant::Integer(right)) => {
+            wrap(integer(left, right), type_).map(Constant::Integer)
+        }
+        (Constant::Float(left), Constant::Float(right)) if type_.is_float() => {
+            Some(Constant::Float(round(float(left, right), type_)))
+        }
+
+        _ => None,
+    }
+}
+
+fn integer(value: Constant) -> Option<i128> {
+    match value {
+        Constant::Integer(value) => Some(value),
+
+        _ => None,
+    }
+}
+
+/// Keeps the low bits of `value` that fit into the integer type, read as
+/// signed or unsigned depending on the type.
+fn wrap(value: i128, type_: &Type) -> Option<i128> {
+    let bits = type_.integer_bits()?;
+    let value = value & ((1 << bits) - 1);
+
+    if type_.is_signed() && value >> (bits - 1) == 1 {
+        Some(value - (1 << bits))
+    } else {
+        Some(value)
+    }
+}
+
+/// Rounds a float to the precision of its type.
+fn round(value: f64, type_: &Type) -> f64 {
+    match type_ {
+        Type::F32 => value as f32 as f64,
+
+        _ => value,
+    }
+}
+
+/// Rounds towards zero and saturates at the bounds of the integer type, NaN
+/// becomes `0`.
+fn saturate(value: f64, type_: &Type) -> Option<i128> {
+    let (min, max) = type_.integer_range()?;
+
+    let value = if value.is_nan() {
+        0
+    } else if value <= min as f64 {
+        min
+    } else if value >= max as f64 {
+        max
+    } else {
+        value.trunc() as i128
+    };
+
+    Some(value)
+}
+
+/// Integers that are not a Unicode scalar value become U+FFFD.
+fn character(value: i128) -> char {
+    u32::try_from(value)
+        .ok()
+        .and_then(char::from_u32)
+        .unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+fn field_index(pool: &AST, context: &Context, struct_: &Type, name: &str) -> Option<usize> {
+    let struct_ = match struct_ {
+        Type::Struct(struct_) => context.resolution.struct_(struct_)?,
+
+        _ => return None,
+    };
+
+    pool.get(struct_).field(pool, name).map(|(index, _)| index)
+}
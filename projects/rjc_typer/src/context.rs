@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use rjc_ast::{
+    expression::{Expression, LiteralValue},
+    ASTRef, Global, Symbol, Type, AST,
+};
+use rjc_diagnostics::{codes, Diagnostic, Diagnostics};
+use rjc_resolver::Resolution;
+
+use crate::{Constant, TypeCheck, Types};
+
+pub struct Context<'a> {
+    diagnostics: &'a mut Diagnostics,
+    pub types: Types,
+    pub resolution: &'a Resolution,
+    pub return_types: Vec<Type>,
+    /// Values of the globals evaluated so far, `None` after an error.
+    pub constants: HashMap<ASTRef<Global>, Option<Constant>>,
+    /// Globals whose value is being evaluated, to find cycles.
+    pub evaluating: Vec<ASTRef<Global>>,
+    expected: Option<Type>,
+}
+
+/// Types an operator accepts for its operands.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Operands {
+    Any,
+    /// Types that can be compared for equality.
+    Equality,
+    Scalar,
+    Number,
+    Integer,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(resolution: &'a Resolution, diagnostics: &'a mut Diagnostics) -> Context<'a> {
+        Context {
+            diagnostics,
+            types: Types::default(),
+            resolution,
+            return_types: Vec::new(),
+            constants: HashMap::new(),
+            evaluating: Vec::new(),
+            expected: None,
+        }
+    }
+
+    pub fn diagnostic(&mut self, diagnostic: Diagnostic) {
+        self.diagnostics.push(diagnostic);
+    }
+
+    pub fn error(&mut self, code: &'static str, symbol: &Symbol, message: String) {
+        self.diagnostics
+            .push(Diagnostic::error(code, message, symbol));
+    }
+
+    /// Types an expression and records the result.
+    pub fn expression(&mut self, pool: &AST, expression: ASTRef<Expression>) -> Type {
+        self.expression_expecting(pool, expression, None)
+    }
+
+    /// Types an expression whose surroundings expect it to be of type
+    /// `expected`, which decides the type of integer literals without a
+    /// suffix. The type is not checked against `expected`.
+    pub fn expression_expecting(
+        &mut self,
+        pool: &AST,
+        expression: ASTRef<Expression>,
+        expected: Option<&Type>,
+    ) -> Type {
+        self.expected = expected.cloned();
+
+        let type_ = pool.get(expression).check(pool, self);
+
+        self.record(expression, type_.clone());
+
+        type_
+    }
+
+    /// The type expected of the expression being checked, only valid before
+    /// its operands are checked.
+    pub fn take_expected(&mut self) -> Option<Type> {
+        self.expected.take()
+    }
+
+    /// Types the operands of an operator, which all need to be of the same
+    /// type. Numeric literals without a suffix take the type of the first
+    /// other operand, or `expected` if there is none. `kind` restricts the
+    /// type of the operands. Returns the type of the operands.
+    pub fn operands(
+        &mut self,
+        pool: &AST,
+        operands: &[ASTRef<Expression>],
+        expected: Option<&Type>,
+        kind: Operands,
+    ) -> Type {
+        let leading = operands
+            .iter()
+            .position(|operand| !is_untyped_literal(pool, pool.get(*operand)))
+            .unwrap_or(0);
+
+        let mut type_ = self.expression_expecting(pool, operands[leading], expected);
+
+        let (valid, name) = match kind {
+            Operands::Any => (true, ""),
+            Operands::Equality => {
+                let valid = type_.is_scalar() || type_ == Type::Str;
+
+                (valid, "number, bool, char or str")
+            }
+            Operands::Scalar => (type_.is_scalar(), "number, bool or char"),
+            Operands::Number => (type_.is_number(), "number"),
+            Operands::Integer => (type_.is_integer(), "integer"),
+        };
+
+        if !valid && type_ != Type::Unknown {
+            let symbol = pool.get(operands[leading]).symbol(pool);
+            let message = format!("expected {}, found `{}`", name, type_);
+
+            self.error(codes::INVALID_OPERAND, symbol, message);
+            type_ = Type::Unknown;
+        }
+
+        for (i, operand) in operands.iter().enumerate() {
+            if i == leading {
+                continue;
+            }
+
+            let found = self.expression_expecting(pool, *operand, Some(&type_));
+
+            self.expect(pool.get(*operand).symbol(pool), &type_, &found);
+        }
+
+        type_
+    }
+
+    pub fn record(&mut self, expression: ASTRef<Expression>, type_: Type) {
+        self.types.insert(expression, type_);
+    }
+
+    /// Reports a mismatch unless `found` is `expected`. Unknown types stem
+    /// from earlier errors and are not reported again.
+    pub fn expect(&mut self, symbol: &Symbol, expected: &Type, found: &Type) {
+        if expected == found || *expected == Type::Unknown || *found == Type::Unknown {
+            return;
+        }
+
+        let diagnostic = Diagnostic::error(
+            codes::MISMATCHED_TYPES,
+            "mismatched types".to_string(),
+            symbol,
+        )
+        .with_message(format!("expected `{}`, found `{}`", expected, found));
+
+        self.diagnostics.push(diagnostic);
+    }
+}
+
+fn is_untyped_literal(pool: &AST, expression: &Expression) -> bool {
+    match expression {
+        Expression::Literal((node, _)) => {
+            let literal = pool.get(*node);
+
+            let number = matches!(
+                literal.value,
+                LiteralValue::Integer { .. } | LiteralValue::Float { .. }
+            );
+
+            number && literal.suffix().is_none()
+        }
+
+        _ => false,
+    }
+}
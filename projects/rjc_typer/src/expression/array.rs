@@ -0,0 +1,48 @@
+use rjc_ast::{
+    expression::{ArrayLiteral, ArrayRepeat},
+    Type, AST,
+};
+
+use crate::{
+    context::{Context, Operands},
+    TypeCheck,
+};
+
+impl TypeCheck for ArrayLiteral {
+    type Output = Type;
+
+    /// All elements are of the same type, unsuffixed literals take the type
+    /// of the other elements or the expected element type.
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let expected = element(context.take_expected());
+        let type_ = context.operands(pool, &self.elements, expected.as_ref(), Operands::Any);
+
+        match type_ {
+            Type::Unknown => Type::Unknown,
+            type_ => Type::Array(Box::new(type_), self.elements.len()),
+        }
+    }
+}
+
+impl TypeCheck for ArrayRepeat {
+    type Output = Type;
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let expected = element(context.take_expected());
+        let type_ = context.expression_expecting(pool, self.value, expected.as_ref());
+
+        match type_ {
+            Type::Unknown => Type::Unknown,
+            type_ => Type::Array(Box::new(type_), self.length),
+        }
+    }
+}
+
+/// The element type expected of an array literal.
+fn element(expected: Option<Type>) -> Option<Type> {
+    match expected {
+        Some(Type::Array(element, _)) => Some(*element),
+
+        _ => None,
+    }
+}
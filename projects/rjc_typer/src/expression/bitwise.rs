@@ -0,0 +1,19 @@
+use rjc_ast::{expression::Bitwise, Type, AST};
+
+use crate::{
+    context::{Context, Operands},
+    TypeCheck,
+};
+
+impl TypeCheck for Bitwise {
+    type Output = Type;
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let expected = context.take_expected();
+        let operands: Vec<_> = std::iter::once(self.first)
+            .chain(self.rest.iter().map(|op| op.expression()))
+            .collect();
+
+        context.operands(pool, &operands, expected.as_ref(), Operands::Integer)
+    }
+}
@@ -0,0 +1,28 @@
+use rjc_ast::{expression::Cast, Type, AST};
+use rjc_diagnostics::codes;
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for Cast {
+    type Output = Type;
+
+    /// Numbers can be converted to any number type, bools to any integer
+    /// type. Chars and integers can be converted into each other.
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let type_ = context.expression(pool, self.expression);
+
+        let valid = type_ == Type::Unknown
+            || (type_.is_number() && self.type_.is_number())
+            || (type_ == Type::Bool && self.type_.is_integer())
+            || (type_ == Type::Char && (self.type_.is_integer() || self.type_ == Type::Char))
+            || (type_.is_integer() && self.type_ == Type::Char);
+
+        if !valid {
+            let message = format!("cannot cast `{}` as `{}`", type_, self.type_);
+
+            context.error(codes::INVALID_CAST, &self.symbol, message);
+        }
+
+        self.type_.clone()
+    }
+}
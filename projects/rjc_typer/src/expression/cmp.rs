@@ -0,0 +1,35 @@
+use rjc_ast::{
+    expression::{Cmp, CmpOp},
+    Type, AST,
+};
+use rjc_diagnostics::codes;
+
+use crate::{
+    context::{Context, Operands},
+    TypeCheck,
+};
+
+impl TypeCheck for Cmp {
+    type Output = Type;
+
+    /// Strings can only be compared for equality, which compares their
+    /// contents.
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let ordered = !matches!(self.op, CmpOp::Eq | CmpOp::Ne);
+        let kind = if ordered {
+            Operands::Scalar
+        } else {
+            Operands::Equality
+        };
+
+        let type_ = context.operands(pool, &[self.left, self.right], None, kind);
+
+        if ordered && type_ == Type::Bool {
+            let message = "ordering comparison is not defined for `bool`".to_string();
+
+            context.error(codes::INVALID_OPERAND, &self.symbol, message);
+        }
+
+        Type::Bool
+    }
+}
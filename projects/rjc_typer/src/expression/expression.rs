@@ -0,0 +1,54 @@
+use rjc_ast::{expression::Expression, Type, AST};
+use rjc_diagnostics::codes;
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for Expression {
+    type Output = Type;
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        match self {
+            Expression::Literal((node, _)) => pool.get(*node).check(pool, context),
+            Expression::Ident((node, _)) => match context.resolution.variable(*node) {
+                Some(variable) => context.types.variable(variable).clone(),
+                None => Type::Unknown,
+            },
+            Expression::Call((node, _)) => {
+                let call = pool.get(*node);
+                let mut types = match call.check(pool, context) {
+                    Some(types) => types,
+                    None => return Type::Unknown,
+                };
+
+                if types.len() == 1 {
+                    return types.remove(0);
+                }
+
+                let name = &pool.get(call.ident).name;
+                let message = match types.len() {
+                    0 => format!("function `{}` does not return a value", name),
+                    n => format!(
+                        "function `{}` returns {} values where a single value is expected",
+                        name, n
+                    ),
+                };
+
+                context.error(codes::NOT_A_SINGLE_VALUE, &call.symbol, message);
+
+                Type::Unknown
+            }
+            Expression::Cmp((node, _)) => pool.get(*node).check(pool, context),
+            Expression::Sum((node, _)) => pool.get(*node).check(pool, context),
+            Expression::Product((node, _)) => pool.get(*node).check(pool, context),
+            Expression::Logical((node, _)) => pool.get(*node).check(pool, context),
+            Expression::Unary((node, _)) => pool.get(*node).check(pool, context),
+            Expression::Bitwise((node, _)) => pool.get(*node).check(pool, context),
+            Expression::Cast((node, _)) => pool.get(*node).check(pool, context),
+            Expression::Field((node, _)) => pool.get(*node).check(pool, context),
+            Expression::StructLiteral((node, _)) => pool.get(*node).check(pool, context),
+            Expression::Index((node, _)) => pool.get(*node).check(pool, context),
+            Expression::ArrayLiteral((node, _)) => pool.get(*node).check(pool, context),
+            Expression::ArrayRepeat((node, _)) => pool.get(*node).check(pool, context),
+        }
+    }
+}
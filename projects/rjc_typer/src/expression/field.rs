@@ -0,0 +1,36 @@
+use rjc_ast::{expression::Field, ASTType, Type, Variable, AST};
+use rjc_diagnostics::codes;
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for Field {
+    type Output = Type;
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let type_ = context.expression(pool, self.expression);
+        let field = pool.get(self.field);
+
+        let struct_ = match &type_ {
+            Type::Struct(name) => match context.resolution.struct_(name) {
+                Some(struct_) => Some(pool.get(struct_)),
+
+                // Undeclared structs are reported by the resolver.
+                None => return Type::Unknown,
+            },
+            Type::Unknown => return Type::Unknown,
+
+            _ => None,
+        };
+
+        match struct_.and_then(|struct_| struct_.field(pool, &field.name)) {
+            Some((_, variable)) => context.types.variable(Variable::pool_ref(variable.id)).clone(),
+            None => {
+                let message = format!("no field `{}` on type `{}`", field.name, type_);
+
+                context.error(codes::UNKNOWN_FIELD, &field.symbol, message);
+
+                Type::Unknown
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use rjc_ast::{
+    expression::{Expression, Index, LiteralValue},
+    Type, AST,
+};
+use rjc_diagnostics::{codes, Diagnostic};
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for Index {
+    type Output = Type;
+
+    /// Arrays are indexed with any integer type, unsuffixed literals are
+    /// `usize`. Constant indices are bounds checked here, all others at
+    /// runtime.
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let type_ = context.expression(pool, self.expression);
+        let index = context.expression_expecting(pool, self.index, Some(&Type::USize));
+
+        let index_symbol = pool.get(self.index).symbol(pool);
+
+        if !index.is_integer() && index != Type::Unknown {
+            let message = format!("expected integer index, found `{}`", index);
+
+            context.error(codes::INVALID_OPERAND, index_symbol, message);
+        }
+
+        let (element, length) = match type_ {
+            Type::Array(element, length) => (*element, length),
+            Type::Unknown => return Type::Unknown,
+
+            _ => {
+                let symbol = pool.get(self.expression).symbol(pool);
+                let message = format!("cannot index into a value of type `{}`", type_);
+
+                context.error(codes::INVALID_OPERAND, symbol, message);
+
+                return Type::Unknown;
+            }
+        };
+
+        if let Expression::Literal((literal, _)) = pool.get(self.index) {
+            match pool.get(*literal).value {
+                LiteralValue::Integer { value, .. } if value >= length as i128 => {
+                    let diagnostic = Diagnostic::error(
+                        codes::INDEX_OUT_OF_BOUNDS,
+                        "index out of bounds".to_string(),
+                        index_symbol,
+                    )
+                    .with_message(format!(
+                        "the length is {} but the index is {}",
+                        length, value
+                    ));
+
+                    context.diagnostic(diagnostic);
+                }
+
+                _ => {}
+            }
+        }
+
+        element
+    }
+}
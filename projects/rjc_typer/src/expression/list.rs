@@ -0,0 +1,51 @@
+use rjc_ast::{
+    expression::{Expression, ExpressionList},
+    Symbol, Type, AST,
+};
+
+use crate::{context::Context, TypeCheck};
+
+/// Types every expression in the list, `expected` holds the types the values
+/// are expected to have. A call contributes one entry per return value,
+/// matching how the list is evaluated. Returns `None` if the number of values
+/// is unknown because a called function is.
+pub fn check_list(
+    list: &ExpressionList,
+    pool: &AST,
+    context: &mut Context,
+    expected: &[Type],
+) -> Option<Vec<(Type, Symbol)>> {
+    let mut types = Some(vec![]);
+
+    for expression in &list.list {
+        let symbol = pool.get(*expression).symbol(pool);
+        let index = types.as_ref().map_or(usize::MAX, |types| types.len());
+
+        let entries = match pool.get(*expression) {
+            Expression::Call((node, _)) => {
+                let return_types = pool.get(*node).check(pool, context);
+
+                match return_types.as_deref() {
+                    Some([type_]) => context.record(*expression, type_.clone()),
+                    _ => context.record(*expression, Type::Unknown),
+                }
+
+                return_types
+            }
+
+            _ => Some(vec![context.expression_expecting(
+                pool,
+                *expression,
+                expected.get(index),
+            )]),
+        };
+
+        if let (Some(types), Some(entries)) = (&mut types, entries) {
+            types.extend(entries.into_iter().map(|type_| (type_, symbol.clone())));
+        } else {
+            types = None;
+        }
+    }
+
+    types
+}
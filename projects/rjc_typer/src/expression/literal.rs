@@ -0,0 +1,65 @@
+use rjc_ast::{
+    expression::{Literal, LiteralValue},
+    ASTType, Type, AST,
+};
+use rjc_diagnostics::{codes, Diagnostic};
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for Literal {
+    type Output = Type;
+
+    /// Integer literals without a suffix are of the expected integer type,
+    /// or `i32` if no integer is expected. Float literals likewise default
+    /// to `f64`. Literals with a suffix are range checked by the parser, the
+    /// others are checked against the type they end up with.
+    fn check(&self, _: &AST, context: &mut Context) -> Self::Output {
+        let expected = context.take_expected();
+
+        let type_ = match (&self.value, expected) {
+            (LiteralValue::Bool(_), _) => Type::Bool,
+            (LiteralValue::Char(_), _) => Type::Char,
+            (LiteralValue::Str(_), _) => Type::Str,
+            (LiteralValue::Integer { suffix: Some(suffix), .. }, _)
+            | (LiteralValue::Float { suffix: Some(suffix), .. }, _) => suffix.clone(),
+            (LiteralValue::Float { .. }, Some(expected)) if expected.is_float() => expected,
+            (LiteralValue::Float { .. }, _) => Type::F64,
+            (LiteralValue::Integer { .. }, Some(expected)) if expected.is_integer() => expected,
+            (LiteralValue::Integer { .. }, _) => Type::I32,
+        };
+
+        match self.value {
+            LiteralValue::Integer { value, suffix: None } => {
+                let (min, max) = type_.integer_range().expect("integer literal type");
+
+                if value < min || max < value {
+                    let diagnostic = Diagnostic::error(
+                        codes::LITERAL_OUT_OF_RANGE,
+                        format!("literal out of range for `{}`", type_),
+                        &self.symbol,
+                    )
+                    .with_note(format!("the range of `{}` is `{}..={}`", type_, min, max));
+
+                    context.diagnostic(diagnostic);
+                }
+            }
+            LiteralValue::Float { value, suffix: None }
+                if type_ == Type::F32 && !(value as f32).is_finite() =>
+            {
+                context.error(
+                    codes::LITERAL_OUT_OF_RANGE,
+                    &self.symbol,
+                    format!("literal out of range for `{}`", type_),
+                );
+            }
+
+            _ => {}
+        }
+
+        context
+            .types
+            .insert_literal(Self::pool_ref(self.id), type_.clone());
+
+        type_
+    }
+}
@@ -0,0 +1,30 @@
+use rjc_ast::{
+    expression::{Logical, LogicalOp},
+    Type, AST,
+};
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for Logical {
+    type Output = Type;
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let mut operands = vec![self.first];
+
+        for op in &self.rest {
+            match op {
+                LogicalOp::And(expression) | LogicalOp::Or(expression) => {
+                    operands.push(*expression)
+                }
+            }
+        }
+
+        for operand in operands {
+            let type_ = context.expression(pool, operand);
+
+            context.expect(pool.get(operand).symbol(pool), &Type::Bool, &type_);
+        }
+
+        Type::Bool
+    }
+}
@@ -0,0 +1,15 @@
+mod array;
+mod bitwise;
+mod cast;
+mod cmp;
+#[allow(clippy::module_inception)]
+mod expression;
+mod field;
+mod index;
+pub mod list;
+mod literal;
+mod logical;
+mod product;
+mod struct_literal;
+mod sum;
+mod unary;
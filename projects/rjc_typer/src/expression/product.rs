@@ -0,0 +1,57 @@
+use rjc_ast::{
+    expression::{Expression, LiteralValue, Product, ProductOp},
+    Type, AST,
+};
+use rjc_diagnostics::codes;
+
+use crate::{
+    context::{Context, Operands},
+    TypeCheck,
+};
+
+impl TypeCheck for Product {
+    type Output = Type;
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let expected = context.take_expected();
+        let mut operands = vec![self.first];
+
+        for op in &self.rest {
+            match op {
+                ProductOp::Mul(expression)
+                | ProductOp::Div(expression)
+                | ProductOp::Rem(expression) => operands.push(*expression),
+            }
+        }
+
+        let type_ = context.operands(pool, &operands, expected.as_ref(), Operands::Number);
+
+        for op in &self.rest {
+            let divisor = match op {
+                ProductOp::Mul(_) => continue,
+                ProductOp::Div(expression) | ProductOp::Rem(expression) => *expression,
+            };
+
+            if type_.is_integer() && is_zero(pool, pool.get(divisor)) {
+                context.error(
+                    codes::DIVISION_BY_ZERO,
+                    pool.get(divisor).symbol(pool),
+                    "attempt to divide by zero".to_string(),
+                );
+            }
+        }
+
+        type_
+    }
+}
+
+fn is_zero(pool: &AST, expression: &Expression) -> bool {
+    match expression {
+        Expression::Literal((node, _)) => matches!(
+            pool.get(*node).value,
+            LiteralValue::Integer { value: 0, .. }
+        ),
+
+        _ => false,
+    }
+}
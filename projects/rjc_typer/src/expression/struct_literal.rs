@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+use rjc_ast::{expression::StructLiteral, ASTType, Type, Variable, AST};
+use rjc_diagnostics::codes;
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for StructLiteral {
+    type Output = Type;
+
+    /// Every field of the struct has to be given exactly once, in any order.
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let ident = pool.get(self.ident);
+        let struct_ = context
+            .resolution
+            .struct_(&ident.name)
+            .map(|struct_| pool.get(struct_));
+
+        let mut given = HashSet::new();
+
+        for field in &self.fields {
+            let field = pool.get(*field);
+            let name = pool.get(field.ident);
+            let declared = struct_.and_then(|struct_| struct_.field(pool, &name.name));
+            let expected = declared.map(|(_, variable)| {
+                context.types.variable(Variable::pool_ref(variable.id)).clone()
+            });
+
+            let found = context.expression_expecting(pool, field.expression, expected.as_ref());
+
+            match &expected {
+                Some(expected) => {
+                    context.expect(pool.get(field.expression).symbol(pool), expected, &found)
+                }
+                None if struct_.is_some() => {
+                    let message = format!("struct `{}` has no field `{}`", ident.name, name.name);
+
+                    context.error(codes::UNKNOWN_FIELD, &name.symbol, message);
+                }
+                None => (),
+            }
+
+            if !given.insert(name.name.as_str()) {
+                let message = format!("field `{}` specified more than once", name.name);
+
+                context.error(codes::DUPLICATE_FIELD, &name.symbol, message);
+            }
+        }
+
+        let struct_ = match struct_ {
+            Some(struct_) => struct_,
+
+            // Undeclared structs are reported by the resolver.
+            None => return Type::Unknown,
+        };
+
+        let missing: Vec<_> = struct_
+            .fields
+            .iter()
+            .map(|field| pool.get(pool.get(*field).name).name.as_str())
+            .filter(|name| !given.contains(name))
+            .map(|name| format!("`{}`", name))
+            .collect();
+
+        if !missing.is_empty() {
+            let message = format!(
+                "missing field(s) {} in initializer of `{}`",
+                missing.join(", "),
+                ident.name
+            );
+
+            context.error(codes::MISSING_FIELD, &self.symbol, message);
+        }
+
+        Type::Struct(ident.name.clone())
+    }
+}
@@ -0,0 +1,26 @@
+use rjc_ast::{
+    expression::{Sum, SumOp},
+    Type, AST,
+};
+
+use crate::{
+    context::{Context, Operands},
+    TypeCheck,
+};
+
+impl TypeCheck for Sum {
+    type Output = Type;
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let expected = context.take_expected();
+        let mut operands = vec![self.first];
+
+        for op in &self.rest {
+            match op {
+                SumOp::Add(expression) | SumOp::Sub(expression) => operands.push(*expression),
+            }
+        }
+
+        context.operands(pool, &operands, expected.as_ref(), Operands::Number)
+    }
+}
@@ -0,0 +1,47 @@
+use rjc_ast::{
+    expression::{Unary, UnaryOp},
+    Type, AST,
+};
+use rjc_diagnostics::codes;
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for Unary {
+    type Output = Type;
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let expected = context.take_expected();
+        let symbol = pool.get(self.expression).symbol(pool);
+
+        match self.op {
+            UnaryOp::Not => {
+                let type_ = context.expression(pool, self.expression);
+
+                context.expect(symbol, &Type::Bool, &type_);
+
+                Type::Bool
+            }
+            UnaryOp::Neg | UnaryOp::BitNot => {
+                let type_ = context.expression_expecting(pool, self.expression, expected.as_ref());
+
+                let valid = match self.op {
+                    UnaryOp::Neg => type_.is_signed() || type_.is_float(),
+                    _ => type_.is_integer(),
+                };
+
+                if valid || type_ == Type::Unknown {
+                    return type_;
+                }
+
+                let message = match self.op {
+                    UnaryOp::Neg => format!("expected signed number, found `{}`", type_),
+                    _ => format!("expected integer, found `{}`", type_),
+                };
+
+                context.error(codes::INVALID_OPERAND, symbol, message);
+
+                Type::Unknown
+            }
+        }
+    }
+}
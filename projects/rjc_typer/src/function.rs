@@ -0,0 +1,102 @@
+use rjc_ast::{
+    expression::{Expression, LiteralValue},
+    statement::Statement,
+    ASTRef, Block, Function, Type, AST,
+};
+use rjc_diagnostics::{codes, Diagnostic};
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for Function {
+    type Output = ();
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let ident = pool.get(self.ident);
+
+        // `stdlib/start.ll` calls `main` without arguments and exits with
+        // the returned code.
+        let signature = self.args.is_empty() && self.return_type.list == [Type::I32];
+
+        if ident.name == "main" && !signature {
+            let message = "`main` has to be declared as `fn main(): i32`".to_string();
+
+            context.error(codes::INVALID_MAIN, &ident.symbol, message);
+        }
+
+        context.return_types = self.return_type.list.clone();
+
+        pool.get(self.block).check(pool, context);
+
+        if !self.return_type.list.is_empty() && !returns(pool, self.block) {
+            let diagnostic = Diagnostic::error(
+                codes::MISSING_RETURN,
+                format!("function `{}` may end without returning a value", ident.name),
+                &ident.symbol,
+            )
+            .with_note(format!("`{}` returns `{}`", ident.name, self.return_type));
+
+            context.diagnostic(diagnostic);
+        }
+    }
+}
+
+/// Whether every path through the block ends in a `return`. A `while true`
+/// without a `break` never ends, any other loop may not run its body at all.
+fn returns(pool: &AST, block: ASTRef<Block>) -> bool {
+    pool.get(block)
+        .statements
+        .iter()
+        .any(|statement| match pool.get(*statement) {
+            Statement::Return(_) => true,
+            Statement::If((node, _)) => {
+                let if_ = pool.get(*node);
+
+                match if_.else_block {
+                    Some(else_block) => {
+                        returns(pool, if_.if_block)
+                            && returns(pool, else_block)
+                            && if_
+                                .else_ifs
+                                .iter()
+                                .all(|else_if| returns(pool, pool.get(*else_if).block))
+                    }
+                    None => false,
+                }
+            }
+
+            Statement::While((node, _)) => {
+                let while_ = pool.get(*node);
+
+                let endless = match pool.get(while_.condition) {
+                    Expression::Literal((literal, _)) => {
+                        matches!(pool.get(*literal).value, LiteralValue::Bool(true))
+                    }
+                    _ => false,
+                };
+
+                endless && !breaks(pool, while_.block)
+            }
+
+            _ => false,
+        })
+}
+
+/// Whether the block contains a `break` out of the loop it is the body of.
+/// Nested loops are skipped, their `break`s only end themselves.
+fn breaks(pool: &AST, block: ASTRef<Block>) -> bool {
+    pool.get(block)
+        .statements
+        .iter()
+        .any(|statement| match pool.get(*statement) {
+            Statement::Break(_) => true,
+            Statement::If((node, _)) => {
+                let if_ = pool.get(*node);
+
+                breaks(pool, if_.if_block)
+                    || if_.else_ifs.iter().any(|else_if| breaks(pool, pool.get(*else_if).block))
+                    || if_.else_block.is_some_and(|else_block| breaks(pool, else_block))
+            }
+
+            _ => false,
+        })
+}
@@ -0,0 +1,14 @@
+use rjc_ast::{Global, AST};
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for Global {
+    type Output = ();
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let type_ = context.types.variable(self.variable).clone();
+        let found = context.expression_expecting(pool, self.value, Some(&type_));
+
+        context.expect(pool.get(self.value).symbol(pool), &type_, &found);
+    }
+}
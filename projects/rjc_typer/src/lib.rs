@@ -0,0 +1,37 @@
+use rjc_ast::AST;
+use rjc_diagnostics::Diagnostics;
+use rjc_resolver::Resolution;
+
+mod block;
+mod call;
+mod constant;
+mod context;
+mod expression;
+mod function;
+mod global;
+mod module;
+mod statement;
+mod types;
+mod variable;
+
+use context::Context;
+
+pub use constant::Constant;
+pub use types::Types;
+
+trait TypeCheck {
+    type Output;
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output;
+}
+
+/// Assigns a type to every expression and checks that statements, calls and
+/// operators are used with matching types. Expressions whose type cannot be
+/// determined because of earlier errors are typed as `Type::Unknown`.
+pub fn check(pool: &AST, resolution: &Resolution, diagnostics: &mut Diagnostics) -> Types {
+    let mut context = Context::new(resolution, diagnostics);
+
+    pool.get(pool.module()).check(pool, &mut context);
+
+    context.types
+}
@@ -0,0 +1,34 @@
+use rjc_ast::{Module, AST};
+
+use crate::{constant, context::Context, TypeCheck};
+
+impl TypeCheck for Module {
+    type Output = ();
+
+    /// Fields, globals and arguments are typed up front as they may be used
+    /// before their declaration. Globals are evaluated once all of them are
+    /// typed, as their values may use each other in any order.
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let fields = self.structs.iter().flat_map(|struct_| &pool.get(*struct_).fields);
+        let globals = self.globals.iter().map(|global| &pool.get(*global).variable);
+        let args = self.functions.iter().flat_map(|function| &pool.get(*function).args);
+
+        for variable in fields.chain(globals).chain(args) {
+            pool.get(*variable).check(pool, context);
+        }
+
+        for global in &self.globals {
+            pool.get(*global).check(pool, context);
+        }
+
+        for global in &self.globals {
+            if let Some(value) = constant::global(pool, context, *global) {
+                context.types.insert_global(*global, value);
+            }
+        }
+
+        for function in &self.functions {
+            pool.get(*function).check(pool, context);
+        }
+    }
+}
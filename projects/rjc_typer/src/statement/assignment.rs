@@ -0,0 +1,37 @@
+use rjc_ast::{statement::Assignment, AST};
+use rjc_diagnostics::codes;
+
+use crate::{context::Context, expression::list::check_list, TypeCheck};
+
+impl TypeCheck for Assignment {
+    type Output = ();
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let targets: Vec<_> = self
+            .targets
+            .iter()
+            .map(|target| context.expression(pool, *target))
+            .collect();
+
+        let expressions = pool.get(self.expressions);
+        let values = match check_list(expressions, pool, context, &targets) {
+            Some(values) => values,
+            None => return,
+        };
+
+        if values.len() != self.targets.len() {
+            let message = format!(
+                "cannot assign {} value(s) to {} target(s)",
+                values.len(),
+                self.targets.len()
+            );
+
+            context.error(codes::ASSIGNMENT_COUNT, &expressions.symbol, message);
+            return;
+        }
+
+        for (target, (type_, symbol)) in targets.iter().zip(values) {
+            context.expect(&symbol, target, &type_);
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use rjc_ast::{statement::For, Type, AST};
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for For {
+    type Output = ();
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        for bound in [self.start, self.end] {
+            let type_ = context.expression_expecting(pool, bound, Some(&Type::I32));
+            let symbol = pool.get(bound).symbol(pool);
+
+            context.expect(symbol, &Type::I32, &type_);
+        }
+
+        pool.get(self.variable).check(pool, context);
+        pool.get(self.block).check(pool, context);
+    }
+}
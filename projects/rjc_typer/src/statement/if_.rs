@@ -0,0 +1,40 @@
+use rjc_ast::{
+    statement::{ElseIf, If},
+    Type, AST,
+};
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for If {
+    type Output = ();
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let condition = context.expression(pool, self.condition);
+        let symbol = pool.get(self.condition).symbol(pool);
+
+        context.expect(symbol, &Type::Bool, &condition);
+
+        pool.get(self.if_block).check(pool, context);
+
+        for else_if in &self.else_ifs {
+            pool.get(*else_if).check(pool, context);
+        }
+
+        if let Some(else_block) = self.else_block {
+            pool.get(else_block).check(pool, context);
+        }
+    }
+}
+
+impl TypeCheck for ElseIf {
+    type Output = ();
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let condition = context.expression(pool, self.condition);
+        let symbol = pool.get(self.condition).symbol(pool);
+
+        context.expect(symbol, &Type::Bool, &condition);
+
+        pool.get(self.block).check(pool, context);
+    }
+}
@@ -0,0 +1,31 @@
+use rjc_ast::{statement::Return, AST};
+use rjc_diagnostics::codes;
+
+use crate::{context::Context, expression::list::check_list, TypeCheck};
+
+impl TypeCheck for Return {
+    type Output = ();
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let return_types = context.return_types.clone();
+        let values = match check_list(pool.get(self.expressions), pool, context, &return_types) {
+            Some(values) => values,
+            None => return,
+        };
+
+        if values.len() != return_types.len() {
+            let message = format!(
+                "function returns {} value(s) but {} were returned",
+                return_types.len(),
+                values.len()
+            );
+
+            context.error(codes::RETURN_COUNT, &self.symbol, message);
+            return;
+        }
+
+        for (expected, (type_, symbol)) in return_types.iter().zip(values) {
+            context.expect(&symbol, expected, &type_);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use rjc_ast::{statement::Statement, AST};
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for Statement {
+    type Output = ();
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        match self {
+            Statement::VariableDeclaration((node, _)) => {
+                pool.get(*node).check(pool, context);
+            }
+            Statement::Call((node, _)) => {
+                pool.get(*node).check(pool, context);
+            }
+            Statement::Assignment((node, _)) => pool.get(*node).check(pool, context),
+            Statement::If((node, _)) => pool.get(*node).check(pool, context),
+            Statement::Return((node, _)) => pool.get(*node).check(pool, context),
+            Statement::While((node, _)) => pool.get(*node).check(pool, context),
+            Statement::For((node, _)) => pool.get(*node).check(pool, context),
+            Statement::Break(_) | Statement::Continue(_) => (),
+        }
+    }
+}
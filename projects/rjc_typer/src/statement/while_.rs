@@ -0,0 +1,16 @@
+use rjc_ast::{statement::While, Type, AST};
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for While {
+    type Output = ();
+
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let condition = context.expression(pool, self.condition);
+        let symbol = pool.get(self.condition).symbol(pool);
+
+        context.expect(symbol, &Type::Bool, &condition);
+
+        pool.get(self.block).check(pool, context);
+    }
+}
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use rjc_ast::{
+    expression::{Expression, Literal},
+    ASTRef, Global, Type, Variable,
+};
+
+use crate::Constant;
+
+/// Side table holding the type of every expression, literal and variable,
+/// and the initial value of every global.
+#[derive(Debug, Default)]
+pub struct Types {
+    expressions: HashMap<ASTRef<Expression>, Type>,
+    literals: HashMap<ASTRef<Literal>, Type>,
+    variables: HashMap<ASTRef<Variable>, Type>,
+    globals: HashMap<ASTRef<Global>, Constant>,
+}
+
+impl Types {
+    pub fn insert(&mut self, expression: ASTRef<Expression>, type_: Type) {
+        self.expressions.insert(expression, type_);
+    }
+
+    pub fn expression(&self, expression: ASTRef<Expression>) -> &Type {
+        self.expressions
+            .get(&expression)
+            .expect("expression was not typed")
+    }
+
+    pub fn insert_literal(&mut self, literal: ASTRef<Literal>, type_: Type) {
+        self.literals.insert(literal, type_);
+    }
+
+    pub fn literal(&self, literal: ASTRef<Literal>) -> &Type {
+        self.literals.get(&literal).expect("literal was not typed")
+    }
+
+    pub fn insert_variable(&mut self, variable: ASTRef<Variable>, type_: Type) {
+        self.variables.insert(variable, type_);
+    }
+
+    /// The declared or inferred type of a variable.
+    pub fn variable(&self, variable: ASTRef<Variable>) -> &Type {
+        self.variables.get(&variable).expect("variable was not typed")
+    }
+
+    pub fn insert_global(&mut self, global: ASTRef<Global>, value: Constant) {
+        self.globals.insert(global, value);
+    }
+
+    pub fn global(&self, global: ASTRef<Global>) -> &Constant {
+        self.globals.get(&global).expect("global was not evaluated")
+    }
+}
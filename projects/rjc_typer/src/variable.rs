@@ -0,0 +1,32 @@
+use rjc_ast::{ASTType, Type, Variable, AST};
+
+use crate::{context::Context, TypeCheck};
+
+impl TypeCheck for Variable {
+    type Output = Type;
+
+    /// Records the type of the variable, the declared one or else the one of
+    /// its initial value, which is checked against the declared type.
+    fn check(&self, pool: &AST, context: &mut Context) -> Self::Output {
+        let type_ = match &self.type_ {
+            Some(type_) => {
+                if let Some(value) = self.value {
+                    let found = context.expression_expecting(pool, value, Some(type_));
+
+                    context.expect(pool.get(value).symbol(pool), type_, &found);
+                }
+
+                type_.clone()
+            }
+            None => {
+                let value = self.value.expect("variable without type or value");
+
+                context.expression(pool, value)
+            }
+        };
+
+        context.types.insert_variable(Self::pool_ref(self.id), type_.clone());
+
+        type_
+    }
+}
@@ -1,7 +1,10 @@
 
 declare void @exit(i32)
+declare i64 @write(i32, i8*, i64)
 declare void @main(i32*)
 
+@divide_by_zero.message = private constant [33 x i8] c"panic: attempt to divide by zero\0A"
+
 define void @_start() {
   %1 = alloca i32
 
@@ -14,3 +17,73 @@ define void @_start() {
   call void @exit(i32 %2)
   unreachable
 }
+
+; Called by generated code on division by zero
+define void @rj_divide_by_zero() {
+  %message = getelementptr [33 x i8], [33 x i8]* @divide_by_zero.message, i64 0, i64 0
+  call i64 @write(i32 2, i8* %message, i64 33)
+  call void @exit(i32 101)
+  unreachable
+}
+
+@index_out_of_bounds.len = private constant [39 x i8] c"panic: index out of bounds: the len is "
+@index_out_of_bounds.index = private constant [18 x i8] c" but the index is "
+@newline = private constant [1 x i8] c"\0A"
+
+; Called by generated code when an array index is not below the length
+define void @rj_index_out_of_bounds(i64 %index, i64 %len) {
+  %len.message = getelementptr [39 x i8], [39 x i8]* @index_out_of_bounds.len, i64 0, i64 0
+  call i64 @write(i32 2, i8* %len.message, i64 39)
+  call void @rj_write_u64(i32 2, i64 %len)
+  %index.message = getelementptr [18 x i8], [18 x i8]* @index_out_of_bounds.index, i64 0, i64 0
+  call i64 @write(i32 2, i8* %index.message, i64 18)
+  call void @rj_write_u64(i32 2, i64 %index)
+  %newline = getelementptr [1 x i8], [1 x i8]* @newline, i64 0, i64 0
+  call i64 @write(i32 2, i8* %newline, i64 1)
+  call void @exit(i32 101)
+  unreachable
+}
+
+; Writes the decimal digits of %value to %fd, filling a buffer from the end
+define private void @rj_write_u64(i32 %fd, i64 %value) {
+entry:
+  %buffer = alloca [20 x i8]
+  br label %loop
+
+loop:
+  %position = phi i64 [ 20, %entry ], [ %next.position, %loop ]
+  %rest = phi i64 [ %value, %entry ], [ %next.rest, %loop ]
+  %next.position = sub i64 %position, 1
+  %digit = urem i64 %rest, 10
+  %digit.byte = trunc i64 %digit to i8
+  %char = add i8 %digit.byte, 48
+  %slot = getelementptr [20 x i8], [20 x i8]* %buffer, i64 0, i64 %next.position
+  store i8 %char, i8* %slot
+  %next.rest = udiv i64 %rest, 10
+  %more = icmp ne i64 %next.rest, 0
+  br i1 %more, label %loop, label %done
+
+done:
+  %start = getelementptr [20 x i8], [20 x i8]* %buffer, i64 0, i64 %next.position
+  %length = sub i64 20, %next.position
+  call i64 @write(i32 %fd, i8* %start, i64 %length)
+  ret void
+}
+
+declare i32 @memcmp(i8*, i8*, i64)
+
+; Called by generated code to compare strings for equality
+define zeroext i1 @rj_str_eq(i8* %left, i64 %left.len, i8* %right, i64 %right.len) {
+entry:
+  %same.len = icmp eq i64 %left.len, %right.len
+  br i1 %same.len, label %compare, label %done
+
+compare:
+  %order = call i32 @memcmp(i8* %left, i8* %right, i64 %left.len)
+  %same.bytes = icmp eq i32 %order, 0
+  br label %done
+
+done:
+  %equal = phi i1 [ false, %entry ], [ %same.bytes, %compare ]
+  ret i1 %equal
+}